@@ -0,0 +1,65 @@
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs::{read, read_dir, write};
+use std::path::{Path, PathBuf};
+
+// every file under a directory, in a stable order
+fn files(directory: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for entry in read_dir(directory).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(self::files(&path));
+        } else {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files
+}
+
+// a hash of everything that goes into a crate's code, so editing a day changes only its id
+fn hash_crate(hasher: &mut Sha256, directory: &Path) {
+    println!("cargo:rerun-if-changed={}", directory.display());
+
+    let mut paths = vec![directory.join("Cargo.toml")];
+    paths.extend(files(&directory.join("src")));
+
+    for path in paths {
+        let relative = path.strip_prefix(directory).unwrap();
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update(read(&path).unwrap());
+    }
+}
+
+fn main() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut build_ids = String::new();
+
+    // new days are registered there
+    println!(
+        "cargo:rerun-if-changed={}",
+        workspace.join("Cargo.toml").display()
+    );
+
+    for day in 1..=25 {
+        let directory = workspace.join(format!("day{day}"));
+        if !directory.join("Cargo.toml").exists() {
+            continue;
+        }
+
+        // the shared crates can change any day's answers
+        let mut hasher = Sha256::new();
+        hash_crate(&mut hasher, &directory);
+        hash_crate(&mut hasher, &workspace.join("common"));
+        hash_crate(&mut hasher, &workspace.join("grid"));
+
+        let id = format!("{:x}", hasher.finalize());
+        build_ids.push_str(&format!("pub const DAY{day}: &str = \"{}\";\n", &id[..16]));
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("build_ids.rs");
+    write(out, build_ids).unwrap();
+}
@@ -0,0 +1,175 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+// held while measuring, as the counts above are shared by the whole process
+static MEASURING: Mutex<()> = Mutex::new(());
+
+// the system allocator, keeping count of what passes through it
+pub struct CountingAllocator;
+
+fn allocated(size: usize) {
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+
+    let live = LIVE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+// SAFETY: every call is passed straight on to the system allocator
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        freed(layout.size());
+    }
+
+    // counted as a fresh allocation of the new size, as growing a Vec usually is
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_pointer
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct AllocationStats {
+    pub count: u64,
+    pub bytes: u64,
+    // the most heap that was live at once, over what was live beforehand. Not resident memory:
+    // stacks, and pages the allocator keeps hold of after a free, aren't counted.
+    pub peak_heap_bytes: u64,
+    // the most memory the whole process had resident at once, stacks and all, where Linux's
+    // /proc/self lets it be read and reset
+    pub peak_resident_bytes: Option<u64>,
+}
+
+// VmHWM, the process's peak resident set since it was last reset
+fn peak_resident_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(kilobytes * 1024)
+}
+
+// brings VmHWM down to what's resident now
+fn reset_peak_resident() -> bool {
+    fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+// what f allocated, when built with the alloc-stats feature. Measurements wait for each other,
+// but the counts are for the whole process: anything else allocating meanwhile, such as a solver
+// left running past its timeout, is counted as f's.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+
+    let _measuring = MEASURING.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    // a peak that couldn't be reset would be from before f
+    let resident = reset_peak_resident();
+
+    let result = f();
+
+    let stats = AllocationStats {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak_heap_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+        peak_resident_bytes: resident.then(peak_resident_bytes).flatten(),
+    };
+
+    (result, Some(stats))
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+
+    if bytes >= 1024.0 * 1024.0 * 1024.0 {
+        format!("{:.2}GiB", bytes / (1024.0 * 1024.0 * 1024.0))
+    } else if bytes >= 1024.0 * 1024.0 {
+        format!("{:.2}MiB", bytes / (1024.0 * 1024.0))
+    } else if bytes >= 1024.0 {
+        format!("{:.2}KiB", bytes / 1024.0)
+    } else {
+        format!("{bytes}B")
+    }
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measure_counts_allocations_and_peak() {
+        let ((), stats) = measure(|| {
+            let small = vec![0u8; 1000];
+            drop(small);
+            let large = vec![0u8; 5000];
+            drop(large);
+        });
+        let stats = stats.unwrap();
+
+        assert!(stats.count >= 2);
+        assert!(stats.bytes >= 6000);
+        assert!(stats.peak_heap_bytes >= 5000, "{stats:?}");
+    }
+
+    #[test]
+    fn test_measure_resets_peak_resident() {
+        // fill the pages, so they're resident rather than just reserved
+        let ((), stats) = measure(|| drop(vec![1u8; 64 * 1024 * 1024]));
+        let Some(first) = stats.unwrap().peak_resident_bytes else {
+            // not Linux, or /proc isn't mounted
+            return;
+        };
+        assert!(first >= 64 * 1024 * 1024, "{first}");
+
+        let ((), stats) = measure(|| drop(vec![1u8; 1024]));
+        let second = stats.unwrap().peak_resident_bytes.unwrap();
+        assert!(second < first, "{second} after {first}");
+    }
+}
@@ -0,0 +1,74 @@
+use crate::day_directory;
+use common::input::normalize;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+// recorded answers for a day, keyed by input file name relative to the day's directory
+pub type Manifest = BTreeMap<String, Expected>;
+
+pub fn load(day: u8) -> Result<Manifest, String> {
+    let path = day_directory(day).join("answers.toml");
+
+    let contents = match read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Manifest::new()),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    };
+
+    toml::from_str(&contents).map_err(|e| format!("Failed to parse {}: {e}", path.display()))
+}
+
+// the answers recorded for whichever of the day's files has the same contents, once normalized
+pub fn find(day: u8, input: &str) -> Result<Option<Expected>, String> {
+    let Ok(input) = normalize(input) else {
+        return Ok(None);
+    };
+
+    for (file, expected) in load(day)? {
+        let path = day_directory(day).join(&file);
+        let contents =
+            read_to_string(&path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+
+        if normalize(&contents).is_ok_and(|contents| contents == input) {
+            return Ok(Some(expected));
+        }
+    }
+
+    Ok(None)
+}
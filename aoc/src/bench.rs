@@ -0,0 +1,262 @@
+use crate::alloc::{self, format_bytes, AllocationStats};
+use crate::solvers::Solver;
+use common::{cancel, Error};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+pub struct Statistics {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len().max(1) as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count;
+
+        let middle = nanos.len() / 2;
+        let median = match nanos.len() {
+            0 => 0.0,
+            len if len.is_multiple_of(2) => (nanos[middle - 1] + nanos[middle]) / 2.0,
+            _ => nanos[middle],
+        };
+
+        Self {
+            mean: Duration::from_nanos(mean as u64),
+            median: Duration::from_nanos(median as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub parse: Statistics,
+    pub solve: Statistics,
+    // the mean count and bytes per run, and the highest peak heap and resident use
+    pub allocations: Option<AllocationStats>,
+}
+
+// solves the input `runs` times, parsing afresh each time
+pub fn run(solver: &Solver, input: &str, part: u8, runs: usize) -> Result<Benchmark, Error> {
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut solve_times = Vec::with_capacity(runs);
+    let mut allocations: Option<AllocationStats> = None;
+
+    for _ in 0..runs {
+        let (solved, stats) =
+            cancel::on_solver_thread(|| alloc::measure(|| (solver.solve)(input, part)))?;
+        let solved = solved?;
+        parse_times.push(solved.parse_time);
+        solve_times.push(solved.solve_time);
+
+        if let Some(stats) = stats {
+            let total = allocations.get_or_insert_with(AllocationStats::default);
+            total.count += stats.count;
+            total.bytes += stats.bytes;
+            total.peak_heap_bytes = total.peak_heap_bytes.max(stats.peak_heap_bytes);
+            total.peak_resident_bytes = total.peak_resident_bytes.max(stats.peak_resident_bytes);
+        }
+    }
+
+    if let Some(total) = &mut allocations {
+        total.count /= runs as u64;
+        total.bytes /= runs as u64;
+    }
+
+    Ok(Benchmark {
+        day: solver.day,
+        part,
+        runs,
+        parse: Statistics::new(&parse_times),
+        solve: Statistics::new(&solve_times),
+        allocations,
+    })
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub day: u8,
+    pub part: u8,
+    pub parse_mean_ns: u64,
+    pub solve_mean_ns: u64,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Baseline {
+    pub results: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        let contents = match read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+        };
+
+        toml::from_str(&contents)
+            .map(Some)
+            .map_err(|e| format!("Failed to parse {}: {e}", path.display()))
+    }
+
+    // results for days and parts not in this run are kept
+    pub fn update(&mut self, benchmarks: &[Benchmark]) {
+        self.results.retain(|entry| {
+            !benchmarks
+                .iter()
+                .any(|b| b.day == entry.day && b.part == entry.part)
+        });
+
+        self.results
+            .extend(benchmarks.iter().map(|benchmark| BaselineEntry {
+                day: benchmark.day,
+                part: benchmark.part,
+                parse_mean_ns: benchmark.parse.mean.as_nanos() as u64,
+                solve_mean_ns: benchmark.solve.mean.as_nanos() as u64,
+            }));
+
+        self.results.sort_by_key(|entry| (entry.day, entry.part));
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            create_dir_all(directory)
+                .map_err(|e| format!("Failed to create {}: {e}", directory.display()))?;
+        }
+
+        let contents =
+            toml::to_string(self).map_err(|e| format!("Failed to write baseline: {e}"))?;
+
+        write(path, contents).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+    }
+
+    fn find(&self, day: u8, part: u8) -> Option<&BaselineEntry> {
+        self.results
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part)
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos >= 1e9 {
+        format!("{:.2}s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else {
+        format!("{nanos}ns")
+    }
+}
+
+fn format_change(baseline_ns: u64, duration: Duration) -> String {
+    if baseline_ns == 0 {
+        return "n/a".to_string();
+    }
+
+    let change = (duration.as_nanos() as f64 / baseline_ns as f64 - 1.0) * 100.0;
+
+    format!("{change:+.1}%")
+}
+
+pub fn report(benchmarks: &[Benchmark], baseline: Option<&Baseline>) -> String {
+    let mut report = format!(
+        "{:>3} {:>4} {:>4}  {:>10} {:>10} {:>10}  {:>10} {:>10} {:>10}",
+        "day", "part", "runs", "parse mean", "median", "stddev", "solve mean", "median", "stddev"
+    );
+
+    let allocations = benchmarks
+        .iter()
+        .any(|benchmark| benchmark.allocations.is_some());
+
+    if allocations {
+        let _ = write!(
+            report,
+            "  {:>8} {:>10} {:>10} {:>10}",
+            "allocs", "allocated", "peak heap", "peak RSS"
+        );
+    }
+
+    if baseline.is_some() {
+        report.push_str("  vs baseline");
+    }
+
+    for benchmark in benchmarks {
+        let _ = write!(
+            report,
+            "\n{:>3} {:>4} {:>4}  {:>10} {:>10} {:>10}  {:>10} {:>10} {:>10}",
+            benchmark.day,
+            benchmark.part,
+            benchmark.runs,
+            format_duration(benchmark.parse.mean),
+            format_duration(benchmark.parse.median),
+            format_duration(benchmark.parse.stddev),
+            format_duration(benchmark.solve.mean),
+            format_duration(benchmark.solve.median),
+            format_duration(benchmark.solve.stddev),
+        );
+
+        if allocations {
+            let stats = benchmark.allocations.unwrap_or_default();
+            let _ = write!(
+                report,
+                "  {:>8} {:>10} {:>10} {:>10}",
+                stats.count,
+                format_bytes(stats.bytes),
+                format_bytes(stats.peak_heap_bytes),
+                stats
+                    .peak_resident_bytes
+                    .map_or_else(|| "n/a".to_string(), format_bytes)
+            );
+        }
+
+        match baseline.map(|baseline| baseline.find(benchmark.day, benchmark.part)) {
+            Some(Some(entry)) => {
+                let _ = write!(
+                    report,
+                    "  parse {}, solve {}",
+                    format_change(entry.parse_mean_ns, benchmark.parse.mean),
+                    format_change(entry.solve_mean_ns, benchmark.solve.mean)
+                );
+            }
+            Some(None) => report.push_str("  not in baseline"),
+            None => {}
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bench::Statistics;
+    use std::time::Duration;
+
+    #[test]
+    fn test_statistics() {
+        let samples: Vec<Duration> = [2, 4, 4, 4, 5, 5, 7, 9]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+
+        let statistics = Statistics::new(&samples);
+
+        assert_eq!(statistics.mean, Duration::from_millis(5));
+        assert_eq!(statistics.median, Duration::from_micros(4500));
+        assert_eq!(statistics.stddev, Duration::from_millis(2));
+    }
+}
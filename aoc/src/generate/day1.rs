@@ -0,0 +1,32 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// size lines of calibration text, each with at least one numeric digit
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let mut line = String::new();
+
+        for _ in 0..rng.gen_range(1..=8) {
+            match rng.gen_range(0..4) {
+                0 => line.push(char::from(b'0' + rng.gen_range(1..=9))),
+                1 => line.push_str(WORDS.choose(rng).unwrap()),
+                _ => line.push(char::from(rng.gen_range(b'a'..=b'z'))),
+            }
+        }
+
+        let digit = char::from(b'0' + rng.gen_range(1..=9));
+        line.insert(rng.gen_range(0..=line.len()), digit);
+
+        input.push_str(&line);
+        input.push('\n');
+    }
+
+    input
+}
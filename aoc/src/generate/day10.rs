@@ -0,0 +1,62 @@
+use super::{column_convex_shape, grid_to_string, outline_corners};
+use grid::{Direction, Grid, Position};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+const PIPES: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+// a size by size field of pipes, with one loop through S traced around the outline of a
+// random shape, and junk pipes everywhere else
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(2);
+    let shape = column_convex_shape(rng, size - 1, size - 1);
+
+    let mut field = Grid::filled(size, size, '.');
+    for position in field.positions().collect::<Vec<_>>() {
+        field[position] = *PIPES.choose(rng).unwrap();
+    }
+
+    let corners: Vec<(Position, Vec<Direction>)> = outline_corners(&shape).collect();
+    for (corner, directions) in &corners {
+        field[*corner] = pipe(directions);
+    }
+
+    let start = corners.choose(rng).unwrap().0;
+    field[start] = 'S';
+
+    // junk beside the start mustn't look like it connects to it
+    for direction in Direction::ALL {
+        let Some(neighbour) = field.step(start, direction) else {
+            continue;
+        };
+
+        let on_loop = corners.iter().any(|(corner, _)| *corner == neighbour);
+        if !on_loop && connects(field[neighbour], direction.reverse()) {
+            field[neighbour] = '.';
+        }
+    }
+
+    grid_to_string(&field)
+}
+
+fn pipe(directions: &[Direction]) -> char {
+    match directions {
+        [Direction::North, Direction::South] => '|',
+        [Direction::East, Direction::West] => '-',
+        [Direction::North, Direction::East] => 'L',
+        [Direction::North, Direction::West] => 'J',
+        [Direction::South, Direction::West] => '7',
+        [Direction::East, Direction::South] => 'F',
+        _ => unreachable!("Shape outlines never touch themselves"),
+    }
+}
+
+fn connects(pipe: char, direction: Direction) -> bool {
+    matches!(
+        (pipe, direction),
+        ('|' | 'L' | 'J', Direction::North)
+            | ('|' | '7' | 'F', Direction::South)
+            | ('-' | 'L' | 'F', Direction::East)
+            | ('-' | 'J' | '7', Direction::West)
+    )
+}
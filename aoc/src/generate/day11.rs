@@ -0,0 +1,22 @@
+use super::grid_to_string;
+use grid::{Grid, Position};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// a size by size image with galaxies scattered over it, leaving some rows and columns empty
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.2)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.2)).collect();
+
+    let mut image = Grid::filled(size, size, '.');
+    for row in (0..size).filter(|&row| !empty_rows[row]) {
+        for column in (0..size).filter(|&column| !empty_columns[column]) {
+            if rng.gen_bool(0.05) {
+                image[Position::new(row, column)] = '#';
+            }
+        }
+    }
+
+    grid_to_string(&image)
+}
@@ -0,0 +1,40 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// size records of up to 20 springs, each made from a real arrangement of its groups with
+// some springs hidden, so there's always at least one way to arrange them
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let length = rng.gen_range(3..=20);
+        let mut springs = String::new();
+        let mut groups = Vec::new();
+
+        springs.push_str(&".".repeat(rng.gen_range(0..3)));
+        loop {
+            let group = rng.gen_range(1..=5);
+            if springs.len() + group > length {
+                break;
+            }
+
+            springs.push_str(&"#".repeat(group));
+            groups.push(group.to_string());
+            springs.push_str(&".".repeat(rng.gen_range(1..=3)));
+        }
+
+        if groups.is_empty() {
+            springs = "#".to_string();
+            groups.push("1".to_string());
+        }
+
+        let hidden: String = springs
+            .chars()
+            .map(|spring| if rng.gen_bool(0.6) { '?' } else { spring })
+            .collect();
+
+        input.push_str(&format!("{hidden} {}\n", groups.join(",")));
+    }
+
+    input
+}
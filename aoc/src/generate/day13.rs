@@ -0,0 +1,136 @@
+use super::grid_to_string;
+use grid::{Grid, Position};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mirror {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+// size patterns, each with exactly one mirror, and exactly one other mirror once a smudge
+// on it is cleaned
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let pattern = loop {
+                if let Some(pattern) = smudged_pattern(rng) {
+                    break pattern;
+                }
+            };
+
+            let pattern = if rng.gen_bool(0.5) {
+                pattern.transpose()
+            } else {
+                pattern
+            };
+
+            grid_to_string(&pattern)
+        })
+        .collect();
+
+    patterns.join("\n")
+}
+
+// mirrored both ways, then smudged somewhere only the horizontal mirror reflects
+fn smudged_pattern(rng: &mut ChaCha8Rng) -> Option<Grid<char>> {
+    let rows = rng.gen_range(5..=17);
+    let columns = rng.gen_range(5..=17);
+
+    let mut pattern = Grid::filled(rows, columns, '.');
+    for position in pattern.positions().collect::<Vec<_>>() {
+        if rng.gen_bool(0.5) {
+            pattern[position] = '#';
+        }
+    }
+
+    let horizontal = rng.gen_range(0..rows - 1);
+    let vertical = rng.gen_range(0..columns - 1);
+
+    for (top, bottom) in (0..=horizontal).rev().zip(horizontal + 1..rows) {
+        for column in 0..columns {
+            pattern[Position::new(bottom, column)] = pattern[Position::new(top, column)];
+        }
+    }
+    for (left, right) in (0..=vertical).rev().zip(vertical + 1..columns) {
+        for row in 0..rows {
+            pattern[Position::new(row, right)] = pattern[Position::new(row, left)];
+        }
+    }
+
+    let reflected_rows: Vec<usize> = (0..=horizontal)
+        .rev()
+        .zip(horizontal + 1..rows)
+        .map(|(top, _)| top)
+        .collect();
+    let unreflected_columns: Vec<usize> = (0..columns)
+        .filter(|&column| {
+            let mirrored = (2 * vertical + 1).checked_sub(column);
+            mirrored.is_none_or(|mirrored| mirrored >= columns)
+        })
+        .collect();
+
+    if unreflected_columns.is_empty() {
+        return None;
+    }
+
+    let row = reflected_rows[rng.gen_range(0..reflected_rows.len())];
+    let column = unreflected_columns[rng.gen_range(0..unreflected_columns.len())];
+    let smudge = Position::new(row, column);
+    pattern[smudge] = flip(pattern[smudge]);
+
+    let original = Mirror::Vertical(vertical);
+    let cleaned = Mirror::Horizontal(horizontal);
+
+    if mirrors(&pattern) != [original] {
+        return None;
+    }
+
+    // cleaning any one spot mustn't reveal any mirror other than the intended one
+    let mut found = false;
+    for position in pattern.positions() {
+        let mut cleaned_pattern = pattern.clone();
+        cleaned_pattern[position] = flip(pattern[position]);
+
+        for mirror in mirrors(&cleaned_pattern) {
+            if mirror == cleaned {
+                found = true;
+            } else if mirror != original {
+                return None;
+            }
+        }
+    }
+
+    found.then_some(pattern)
+}
+
+fn flip(terrain: char) -> char {
+    match terrain {
+        '#' => '.',
+        _ => '#',
+    }
+}
+
+fn mirrors(pattern: &Grid<char>) -> Vec<Mirror> {
+    let horizontal = |pattern: &Grid<char>| -> Vec<usize> {
+        (0..pattern.rows() - 1)
+            .filter(|&mirror| {
+                (0..=mirror)
+                    .rev()
+                    .zip(mirror + 1..pattern.rows())
+                    .all(|(top, bottom)| pattern.row(top) == pattern.row(bottom))
+            })
+            .collect()
+    };
+
+    horizontal(pattern)
+        .into_iter()
+        .map(Mirror::Horizontal)
+        .chain(
+            horizontal(&pattern.transpose())
+                .into_iter()
+                .map(Mirror::Vertical),
+        )
+        .collect()
+}
@@ -0,0 +1,20 @@
+use super::grid_to_string;
+use grid::Grid;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// a size by size dish of round and square rocks
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut dish = Grid::filled(size, size, '.');
+
+    for position in dish.positions().collect::<Vec<_>>() {
+        dish[position] = match rng.gen_range(0..10) {
+            0 => '#',
+            1 | 2 => 'O',
+            _ => '.',
+        };
+    }
+
+    grid_to_string(&dish)
+}
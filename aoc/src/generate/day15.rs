@@ -0,0 +1,28 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// size steps on one line, reusing a pool of labels so lenses get replaced and removed
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..size.div_ceil(4))
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect();
+
+    format!("{}\n", steps.join(","))
+}
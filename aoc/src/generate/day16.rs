@@ -0,0 +1,21 @@
+use super::grid_to_string;
+use grid::Grid;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+// a size by size contraption, mostly empty space between mirrors and splitters
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut contraption = Grid::filled(size, size, '.');
+
+    for position in contraption.positions().collect::<Vec<_>>() {
+        if rng.gen_bool(0.1) {
+            contraption[position] = *DEVICES.choose(rng).unwrap();
+        }
+    }
+
+    grid_to_string(&contraption)
+}
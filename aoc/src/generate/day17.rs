@@ -0,0 +1,17 @@
+use super::grid_to_string;
+use grid::Grid;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// a size by size map of heat losses from 1 to 9, at least 5 wide so the ultra crucible can
+// move far enough to turn
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(5);
+    let mut map = Grid::filled(size, size, '1');
+
+    for position in map.positions().collect::<Vec<_>>() {
+        map[position] = char::from(b'0' + rng.gen_range(1..=9));
+    }
+
+    grid_to_string(&map)
+}
@@ -0,0 +1,85 @@
+use super::{column_convex_shape, outline_corners};
+use grid::{Direction, Position};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+
+const LONGEST_HEX_DISTANCE: usize = 0xfffff;
+
+// a dig plan around the outline of a random shape on a size by size grid, which never crosses
+// itself. The hex instructions trace the same shape with the grid lines spread much further
+// apart, so they form a loop too.
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let shape = column_convex_shape(rng, size, size);
+    let corners: HashMap<Position, Vec<Direction>> = outline_corners(&shape).collect();
+
+    let short = spacing(rng, size, 10);
+    let long = spacing(rng, size, (LONGEST_HEX_DISTANCE / size).min(100_000));
+
+    // start on a turn, so the first and last instructions aren't along the same line
+    let mut turns: Vec<Position> = corners
+        .iter()
+        .filter(|(_, directions)| directions[0] != directions[1].reverse())
+        .map(|(&corner, _)| corner)
+        .collect();
+    turns.sort_by_key(|corner| (corner.row, corner.column));
+    let start = *turns.choose(rng).unwrap();
+
+    let mut lines = Vec::new();
+    let mut corner = start;
+    let mut direction = *corners[&start].choose(rng).unwrap();
+
+    loop {
+        let from = corner;
+        while corners[&corner].contains(&direction) {
+            corner = corner.step(direction).unwrap();
+
+            if corner == start {
+                break;
+            }
+        }
+
+        let (distance, long_distance) = match direction {
+            Direction::North | Direction::South => (
+                short[from.row].abs_diff(short[corner.row]),
+                long[from.row].abs_diff(long[corner.row]),
+            ),
+            Direction::East | Direction::West => (
+                short[from.column].abs_diff(short[corner.column]),
+                long[from.column].abs_diff(long[corner.column]),
+            ),
+        };
+
+        let (letter, digit) = match direction {
+            Direction::East => ('R', 0),
+            Direction::South => ('D', 1),
+            Direction::West => ('L', 2),
+            Direction::North => ('U', 3),
+        };
+        lines.push(format!("{letter} {distance} (#{long_distance:05x}{digit})"));
+
+        if corner == start {
+            break;
+        }
+
+        direction = *corners[&corner]
+            .iter()
+            .find(|&&next| next != direction.reverse())
+            .unwrap();
+    }
+
+    format!("{}\n", lines.join("\n"))
+}
+
+// where each grid line ends up, with random gaps up to widest between them
+fn spacing(rng: &mut ChaCha8Rng, size: usize, widest: usize) -> Vec<usize> {
+    (0..=size)
+        .scan(0, |offset, _| {
+            let this = *offset;
+            *offset += rng.gen_range(1..=widest);
+            Some(this)
+        })
+        .collect()
+}
@@ -0,0 +1,27 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// size games, each with up to six samples naming each colour at most once
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for id in 1..=size.max(1) {
+        let samples: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                colours.shuffle(rng);
+
+                colours[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        input.push_str(&format!("Game {id}: {}\n", samples.join("; ")));
+    }
+
+    input
+}
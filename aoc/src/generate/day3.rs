@@ -0,0 +1,41 @@
+use super::grid_to_string;
+use grid::{Grid, Position};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '@', '/', '=', '%', '&', '-'];
+
+// a size by size schematic of numbers up to three digits long, and symbols, on a field of dots
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut schematic = Grid::filled(size, size, '.');
+
+    for row in 0..size {
+        let mut column = 0;
+
+        while column < size {
+            match rng.gen_range(0..10) {
+                0 | 1 => {
+                    let length = rng.gen_range(1..=3).min(size - column);
+                    let value =
+                        rng.gen_range(10_u32.pow(length as u32 - 1)..10_u32.pow(length as u32));
+
+                    for (offset, digit) in value.to_string().chars().enumerate() {
+                        schematic[Position::new(row, column + offset)] = digit;
+                    }
+
+                    // numbers never run into each other
+                    column += length + 1;
+                }
+                2 => {
+                    schematic[Position::new(row, column)] = *SYMBOLS.choose(rng).unwrap();
+                    column += 1;
+                }
+                _ => column += 1,
+            }
+        }
+    }
+
+    grid_to_string(&schematic)
+}
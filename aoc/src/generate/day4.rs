@@ -0,0 +1,53 @@
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const WINNING: usize = 10;
+const NUMBERS: usize = 25;
+// most cards match few numbers, as in real inputs: the copies they win would otherwise multiply
+// past what fits in a u64 within a hundred cards
+const FEW_MATCHES: [usize; 12] = [0, 0, 0, 0, 0, 0, 1, 1, 1, 2, 2, 3];
+
+// size cards of distinct numbers under 100, none winning copies of cards past the last
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let width = size.to_string().len();
+    let mut input = String::new();
+
+    for card in 1..=size {
+        let matches = if rng.gen_ratio(1, 20) {
+            rng.gen_range(4..=WINNING)
+        } else {
+            *FEW_MATCHES.choose(rng).unwrap()
+        }
+        .min(size - card);
+
+        let pool: Vec<usize> = sample(rng, 99, WINNING + NUMBERS - matches)
+            .into_iter()
+            .map(|i| i + 1)
+            .collect();
+        let (winning, rest) = pool.split_at(WINNING);
+
+        let mut numbers: Vec<usize> = winning[..matches].iter().chain(rest).copied().collect();
+        let mut winning = winning.to_vec();
+        winning.shuffle(rng);
+        numbers.shuffle(rng);
+
+        let format = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        input.push_str(&format!(
+            "Card {card:>width$}: {} | {}\n",
+            format(&winning),
+            format(&numbers)
+        ));
+    }
+
+    input
+}
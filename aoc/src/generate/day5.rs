@@ -0,0 +1,59 @@
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// an almanac whose maps each shuffle size blocks of the numbers below 1000 * size, with a
+// few seed ranges small enough for part 2 to brute force
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1);
+    let limit = 1000 * size;
+
+    let seeds: Vec<String> = (0..rng.gen_range(1..=4))
+        .flat_map(|_| {
+            let start = rng.gen_range(0..limit);
+            let length = rng.gen_range(1..=(limit - start).min(limit / 4).max(1));
+            [start.to_string(), length.to_string()]
+        })
+        .collect();
+
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for map in MAPS {
+        let mut cuts: Vec<usize> = sample(rng, limit - 1, size.min(limit) - 1)
+            .into_iter()
+            .map(|cut| cut + 1)
+            .collect();
+        cuts.push(0);
+        cuts.push(limit);
+        cuts.sort();
+
+        // source blocks in order, laid out again in a random order as destinations
+        let blocks: Vec<(usize, usize)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        let mut order: Vec<usize> = (0..blocks.len()).collect();
+        order.shuffle(rng);
+
+        let mut entries = Vec::new();
+        let mut destination = 0;
+        for &block in &order {
+            let (source, length) = blocks[block];
+            entries.push(format!("{destination} {source} {length}"));
+            destination += length;
+        }
+        entries.shuffle(rng);
+
+        input.push_str(&format!("\n{map} map:\n{}\n", entries.join("\n")));
+    }
+
+    input
+}
@@ -0,0 +1,47 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+// a sheet of size races (at most 4, so the joined race fits), each one beatable, joined or not
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let races = size.clamp(1, 4);
+
+    loop {
+        let times: Vec<u64> = (0..races).map(|_| rng.gen_range(7..100)).collect();
+        let distances: Vec<u64> = times
+            .iter()
+            .map(|&time| rng.gen_range(time..best_distance(time)))
+            .collect();
+
+        let joined_time = join(&times);
+        let joined_distance = join(&distances);
+
+        if joined_distance < best_distance(joined_time) {
+            let width = distances
+                .iter()
+                .chain(&times)
+                .map(|n| n.to_string().len())
+                .max();
+            let row = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>width$}", width = width.unwrap_or(0) + 3))
+                    .collect::<String>()
+            };
+
+            return format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances));
+        }
+    }
+}
+
+fn best_distance(time: u64) -> u64 {
+    (time / 2) * (time - time / 2)
+}
+
+fn join(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
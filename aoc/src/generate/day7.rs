@@ -0,0 +1,28 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+// size distinct hands with bids up to 1000, skewed towards hands with repeated cards
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.clamp(1, 13_usize.pow(5));
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+
+    while hands.len() < size {
+        // drawing from a few labels makes pairs and better likely
+        let count = rng.gen_range(1..=13);
+        let labels: Vec<char> = CARDS.choose_multiple(rng, count).copied().collect();
+        let hand: String = (0..5).map(|_| *labels.choose(rng).unwrap()).collect();
+
+        if hands.insert(hand.clone()) {
+            input.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+        }
+    }
+
+    input
+}
@@ -0,0 +1,71 @@
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const PERIODS: [usize; 6] = [3, 5, 7, 11, 13, 17];
+
+// size directions (at most 200, so node names don't run out), and a loop per ghost through
+// a period times size nodes, reaching its Z node after every full period from its A node.
+// AAA and ZZZ belong to the first ghost.
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let length = size.clamp(1, 200);
+    let directions: Vec<char> = (0..length)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let ghosts = rng.gen_range(1..=PERIODS.len());
+    let periods: Vec<usize> = PERIODS.choose_multiple(rng, ghosts).copied().collect();
+
+    // the last letter is reserved for marking starts and ends
+    let middle_names = 26 * 26 * 24;
+    let loop_lengths: Vec<usize> = periods.iter().map(|period| period * length).collect();
+    let mut names = sample(rng, middle_names, loop_lengths.iter().sum::<usize>())
+        .into_iter()
+        .map(|i| name(i / 24, char::from(b'B' + (i % 24) as u8)));
+
+    // AAA and ZZZ are kept for the first ghost
+    let prefixes = sample(rng, 26 * 26 - 2, ghosts - 1)
+        .into_iter()
+        .map(|i| (name(i + 1, 'A'), name(i + 1, 'Z')));
+    let ends = std::iter::once(("AAA".to_string(), "ZZZ".to_string())).chain(prefixes);
+
+    let mut lines = Vec::new();
+
+    for ((start, end), &loop_length) in ends.zip(&loop_lengths) {
+        // the end is at position 0 of the loop, and the start steps just as it does
+        let ring: Vec<String> = std::iter::once(end)
+            .chain(names.by_ref().take(loop_length - 1))
+            .collect();
+
+        for position in 0..loop_length {
+            let next = &ring[(position + 1) % loop_length];
+            let decoy = &ring[rng.gen_range(0..loop_length)];
+
+            let neighbours = match directions[position % length] {
+                'L' => format!("({next}, {decoy})"),
+                _ => format!("({decoy}, {next})"),
+            };
+
+            if position == 0 {
+                lines.push(format!("{start} = {neighbours}"));
+            }
+            lines.push(format!("{} = {neighbours}", ring[position]));
+        }
+    }
+
+    lines.shuffle(rng);
+
+    format!(
+        "{}\n\n{}\n",
+        directions.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+fn name(index: usize, last: char) -> String {
+    let first = char::from(b'A' + (index / 26) as u8);
+    let second = char::from(b'A' + (index % 26) as u8);
+
+    format!("{first}{second}{last}")
+}
@@ -0,0 +1,30 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+const LENGTH: usize = 21;
+
+// size histories of 21 values, each a polynomial sequence so differencing reaches all zeroes
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        // the first value of each row of differences, from the history down
+        let degree = rng.gen_range(0..=8);
+        let mut leading: Vec<i64> = (0..=degree).map(|_| rng.gen_range(-20..=20)).collect();
+
+        let mut history = Vec::with_capacity(LENGTH);
+        for _ in 0..LENGTH {
+            history.push(leading[0].to_string());
+
+            // step every row of differences along by one
+            for i in 0..degree {
+                leading[i] += leading[i + 1];
+            }
+        }
+
+        input.push_str(&history.join(" "));
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,180 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use grid::{Direction, Grid, Position};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+pub struct Generator {
+    pub day: u8,
+    // size is roughly the number of lines, or the side of a square map
+    pub generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+impl Generator {
+    // the same seed and size always give the same input
+    pub fn run(&self, seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (self.generate)(&mut rng, size)
+    }
+}
+
+pub fn generators() -> Vec<Generator> {
+    vec![
+        Generator {
+            day: 1,
+            generate: day1::generate,
+        },
+        Generator {
+            day: 2,
+            generate: day2::generate,
+        },
+        Generator {
+            day: 3,
+            generate: day3::generate,
+        },
+        Generator {
+            day: 4,
+            generate: day4::generate,
+        },
+        Generator {
+            day: 5,
+            generate: day5::generate,
+        },
+        Generator {
+            day: 6,
+            generate: day6::generate,
+        },
+        Generator {
+            day: 7,
+            generate: day7::generate,
+        },
+        Generator {
+            day: 8,
+            generate: day8::generate,
+        },
+        Generator {
+            day: 9,
+            generate: day9::generate,
+        },
+        Generator {
+            day: 10,
+            generate: day10::generate,
+        },
+        Generator {
+            day: 11,
+            generate: day11::generate,
+        },
+        Generator {
+            day: 12,
+            generate: day12::generate,
+        },
+        Generator {
+            day: 13,
+            generate: day13::generate,
+        },
+        Generator {
+            day: 14,
+            generate: day14::generate,
+        },
+        Generator {
+            day: 15,
+            generate: day15::generate,
+        },
+        Generator {
+            day: 16,
+            generate: day16::generate,
+        },
+        Generator {
+            day: 17,
+            generate: day17::generate,
+        },
+        Generator {
+            day: 18,
+            generate: day18::generate,
+        },
+    ]
+}
+
+fn grid_to_string(grid: &Grid<char>) -> String {
+    format!("{grid}\n")
+}
+
+// a random polyomino whose columns are each one unbroken run of cells, with neighbouring runs
+// sharing at least one row, so its outline is a single loop that never touches itself
+fn column_convex_shape(rng: &mut ChaCha8Rng, rows: usize, columns: usize) -> Grid<bool> {
+    let mut shape = Grid::filled(rows, columns, false);
+
+    // spanning most of the width, for a longer outline
+    let first = rng.gen_range(0..=columns / 4);
+    let last = rng.gen_range((columns - 1 - columns / 4).max(first)..columns);
+
+    let mut top = rng.gen_range(0..rows);
+    let mut bottom = rng.gen_range(top..rows);
+
+    for column in first..=last {
+        if column > first {
+            let new_top = rng.gen_range(0..=bottom);
+            let new_bottom = rng.gen_range(top.max(new_top)..rows);
+            (top, bottom) = (new_top, new_bottom);
+        }
+
+        for row in top..=bottom {
+            shape[Position::new(row, column)] = true;
+        }
+    }
+
+    shape
+}
+
+// the directions of the outline edges meeting at the corner above and left of a cell, so
+// corners run one further than cells in each direction
+fn outline_at(shape: &Grid<bool>, corner: Position) -> Vec<Direction> {
+    let filled = |row: Option<usize>, column: Option<usize>| match (row, column) {
+        (Some(row), Some(column)) => shape.get(Position::new(row, column)) == Some(&true),
+        _ => false,
+    };
+
+    let above = corner.row.checked_sub(1);
+    let below = Some(corner.row);
+    let left = corner.column.checked_sub(1);
+    let right = Some(corner.column);
+
+    Direction::ALL
+        .into_iter()
+        .filter(|direction| match direction {
+            Direction::North => filled(above, left) != filled(above, right),
+            Direction::East => filled(above, right) != filled(below, right),
+            Direction::South => filled(below, left) != filled(below, right),
+            Direction::West => filled(above, left) != filled(below, left),
+        })
+        .collect()
+}
+
+fn outline_corners(shape: &Grid<bool>) -> impl Iterator<Item = (Position, Vec<Direction>)> + '_ {
+    (0..=shape.rows()).flat_map(move |row| {
+        (0..=shape.columns()).filter_map(move |column| {
+            let corner = Position::new(row, column);
+            let outline = outline_at(shape, corner);
+
+            (!outline.is_empty()).then_some((corner, outline))
+        })
+    })
+}
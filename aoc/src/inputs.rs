@@ -0,0 +1,131 @@
+use crate::{day_directory, workspace_directory};
+use std::env;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub fn cache_directory() -> PathBuf {
+    env::var_os("AOC_CACHE_DIR")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("XDG_CACHE_HOME").map(|cache| PathBuf::from(cache).join("aoc")))
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache").join("aoc")))
+        .unwrap_or_else(|| workspace_directory().join("target").join("inputs"))
+}
+
+pub fn session() -> Option<String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+// downloads inputs as the account the session cookie belongs to
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "aoc2023 input cache")
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(404, _) => format!("Day {day} of {year} has no input yet"),
+                ureq::Error::Status(400, _) | ureq::Error::Status(500, _) => {
+                    format!("{url} rejected the session token: check AOC_SESSION")
+                }
+                e => format!("Failed to fetch {url}: {e}"),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|e| format!("Failed to read {url}: {e}"))
+    }
+}
+
+// falls back to the inputs checked in next to each day, for when there's no session token
+pub struct CheckedInFetcher;
+
+impl Fetcher for CheckedInFetcher {
+    fn fetch(&self, _year: u16, day: u8) -> Result<String, String> {
+        let path = day_directory(day).join("input.txt");
+
+        read_to_string(&path).map_err(|e| {
+            format!(
+                "No input cached for day {day}, and {} can't be read ({e}): set AOC_SESSION to download it",
+                path.display()
+            )
+        })
+    }
+}
+
+pub struct InputCache {
+    directory: PathBuf,
+    fetcher: Box<dyn Fetcher>,
+}
+
+impl InputCache {
+    pub fn new(directory: PathBuf, fetcher: Box<dyn Fetcher>) -> Self {
+        Self { directory, fetcher }
+    }
+
+    // AOC_CACHE_DIR, AOC_SESSION and AOC_BASE_URL configure where inputs live and come from
+    pub fn from_env() -> Self {
+        let fetcher: Box<dyn Fetcher> = match session() {
+            Some(session) => Box::new(HttpFetcher::new(&base_url(), &session)),
+            None => Box::new(CheckedInFetcher),
+        };
+
+        Self::new(cache_directory(), fetcher)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.directory
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    // the cached input, fetched and stored first if it's missing
+    pub fn load(&self, year: u16, day: u8) -> Result<(PathBuf, String), String> {
+        let path = self.path(year, day);
+
+        match read_to_string(&path) {
+            Ok(input) => return Ok((path, input)),
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+        }
+
+        let input = self.fetcher.fetch(year, day)?;
+
+        if let Some(directory) = path.parent() {
+            create_dir_all(directory)
+                .map_err(|e| format!("Failed to create {}: {e}", directory.display()))?;
+        }
+        write(&path, &input).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+
+        Ok((path, input))
+    }
+}
@@ -0,0 +1,25 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod generate;
+pub mod inputs;
+pub mod report;
+pub mod results;
+pub mod scaffold;
+pub mod serve;
+pub mod solvers;
+pub mod submit;
+pub mod summary;
+pub mod watch;
+
+use std::path::PathBuf;
+
+pub const YEAR: u16 = 2023;
+
+pub fn workspace_directory() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), ".."].iter().collect()
+}
+
+pub fn day_directory(day: u8) -> PathBuf {
+    workspace_directory().join(format!("day{day}"))
+}
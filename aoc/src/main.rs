@@ -0,0 +1,575 @@
+use aoc::bench::{self, Baseline};
+use aoc::generate::generators;
+use aoc::inputs::{self, InputCache};
+use aoc::report::Report;
+use aoc::results::{self, ResultCache};
+use aoc::scaffold;
+use aoc::serve::{self, Api};
+use aoc::solvers::{solvers, Solver};
+use aoc::submit::{self, History, HttpSubmitter, Outcome};
+use aoc::summary::{self, Status};
+use aoc::watch::Watcher;
+use aoc::{day_directory, workspace_directory, YEAR};
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{trace, Error};
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::time::Duration;
+use tracing::Level;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show how the solvers reach their answers, the same as --trace debug
+    #[arg(long, global = true, conflicts_with = "trace")]
+    explain: bool,
+    /// Show the solvers' tracing events up to this level: error, warn, info, debug or trace
+    #[arg(long, global = true, value_name = "LEVEL")]
+    trace: Option<Level>,
+    /// Write tracing events to this file instead of stderr
+    #[arg(long, global = true, value_name = "PATH")]
+    trace_file: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle, running every available part unless --part is given
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Solve every registered day's cached input at once, and print a table of how each
+        /// part went
+        #[arg(long, conflicts_with_all = ["day", "input", "prefix", "format"])]
+        all: bool,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Any number of files, or - for standard input. Defaults to the day's input from the
+        /// input cache
+        #[arg(long, num_args = 1..)]
+        input: Vec<PathBuf>,
+        /// Label each answer with the input it came from
+        #[arg(long)]
+        prefix: bool,
+        /// json prints an object per part, with the input's hash and timings
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solve again even if an answer for the same input and build is cached
+        #[arg(long)]
+        no_cache: bool,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Time parsing and solving separately over repeated runs of each day's cached input
+    Bench {
+        /// Defaults to every registered day
+        days: Vec<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Record this run as the baseline later runs are compared against
+        #[arg(long)]
+        save_baseline: bool,
+        /// Defaults to target/bench-baseline.toml in the workspace
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Submit an answer, unless earlier submissions already rule it out
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Defaults to solving the day's input
+        #[arg(long)]
+        answer: Option<String>,
+        /// Defaults to the day's input from the input cache
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Defaults to submissions.toml for the year in the input cache directory
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// Print a random input for a day, the same for the same seed and size
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly the number of lines, or the side of a square map
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Solve each input recorded in a day's answers.toml, and say which answers have changed
+    Check {
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Start a new day: a crate with a solver skeleton and a failing sample test, registered
+    /// with the workspace, this runner, the input generators and the fuzzer
+    New { day: u8 },
+    /// Answer solve requests over HTTP on localhost: POST /solve/{day}/{part} with the input as
+    /// the body, or GET /days for the registered days
+    Serve {
+        #[arg(long, default_value_t = 2023)]
+        port: u16,
+        /// Solve again even if an answer for the same input and build is cached
+        #[arg(long)]
+        no_cache: bool,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_seconds, default_value = "10")]
+        timeout: Duration,
+    },
+    /// Parse a day's input once, then explore it with the day's own commands, e.g. map 79 on
+    /// day 5. help lists them
+    Repl {
+        day: u8,
+        /// Defaults to the day's input from the input cache
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Check a day again whenever its sources, inputs or answers change
+    Watch {
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+}
+
+fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("Expected a number of seconds, found '{seconds}'"))
+}
+
+// the input's path and contents
+fn read_input(day: u8, input: Option<PathBuf>) -> Result<(PathBuf, String), String> {
+    match input {
+        Some(path) => match common::input::read(&path) {
+            Ok(input) => Ok((path, input)),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        },
+        None => InputCache::from_env().load(YEAR, day),
+    }
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    inputs: Vec<PathBuf>,
+    prefix: bool,
+    format: Format,
+    no_cache: bool,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let solver = solvers()
+        .into_iter()
+        .find(|solver| solver.day == day)
+        .ok_or_else(|| format!("No solver registered for day {day}"))?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let cache = (!no_cache).then(ResultCache::from_env);
+
+    let run_input = |path: &Path, input: &str| {
+        let label = if prefix {
+            format!("{}: ", path.display())
+        } else {
+            String::new()
+        };
+
+        for &selected_part in &parts {
+            let attempt = results::solve(cache.as_ref(), &solver, input, selected_part, timeout);
+
+            match (&attempt.result, format) {
+                // only complain about missing parts if one was asked for explicitly
+                (Err(Error::Unimplemented), _) if part.is_none() => continue,
+                (_, Format::Json) => {
+                    println!(
+                        "{}",
+                        Report::new(&solver, selected_part, path, input, &attempt).to_json()
+                    )
+                }
+                (Ok(solved), Format::Text) => println!("{label}{}", solved.answer),
+                (Err(_), Format::Text) => {}
+            }
+
+            if let Err(e) = attempt.result {
+                return Err(format!("Day {day} part {selected_part}: {e}"));
+            }
+        }
+
+        Ok(())
+    };
+
+    if inputs.len() <= 1 {
+        let (path, input) = read_input(day, inputs.into_iter().next())?;
+        return run_input(&path, &input);
+    }
+
+    let mut failures = 0;
+
+    // one bad input shouldn't stop the rest being solved
+    for path in &inputs {
+        let result = common::input::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|input| run_input(path, &input));
+
+        if let Err(message) = result {
+            eprintln!("{}: {message}", path.display());
+            failures += 1;
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(format!("{failures} of {} inputs failed", inputs.len())),
+    }
+}
+
+fn run_all(part: Option<u8>, no_cache: bool, timeout: Option<Duration>) -> Result<(), String> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let cache = (!no_cache).then(ResultCache::from_env);
+
+    let rows = summary::run_all(
+        &solvers(),
+        &parts,
+        &InputCache::from_env(),
+        cache.as_ref(),
+        timeout,
+    )?;
+
+    println!("{}", summary::table(&rows));
+
+    match rows.iter().filter(|row| row.status != Status::Ok).count() {
+        0 => Ok(()),
+        failures => Err(format!("{failures} of {} parts failed", rows.len())),
+    }
+}
+
+fn bench(
+    days: Vec<u8>,
+    part: Option<u8>,
+    runs: usize,
+    save_baseline: bool,
+    baseline_path: Option<PathBuf>,
+) -> Result<(), String> {
+    let solvers: Vec<Solver> = solvers()
+        .into_iter()
+        .filter(|solver| days.is_empty() || days.contains(&solver.day))
+        .collect();
+
+    if let Some(day) = days
+        .iter()
+        .find(|&&day| !solvers.iter().any(|solver| solver.day == day))
+    {
+        return Err(format!("No solver registered for day {day}"));
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let cache = InputCache::from_env();
+    let mut benchmarks = Vec::new();
+
+    for solver in &solvers {
+        let (_, input) = cache.load(YEAR, solver.day)?;
+
+        for &selected_part in &parts {
+            match bench::run(solver, &input, selected_part, runs) {
+                Ok(benchmark) => benchmarks.push(benchmark),
+                Err(Error::Unimplemented) if part.is_none() => {}
+                Err(e) => return Err(format!("Day {} part {selected_part}: {e}", solver.day)),
+            }
+        }
+    }
+
+    let baseline_path = baseline_path.unwrap_or_else(|| {
+        workspace_directory()
+            .join("target")
+            .join("bench-baseline.toml")
+    });
+    let baseline = Baseline::load(&baseline_path)?;
+
+    println!("{}", bench::report(&benchmarks, baseline.as_ref()));
+
+    if save_baseline {
+        let mut baseline = baseline.unwrap_or_default();
+        baseline.update(&benchmarks);
+        baseline.save(&baseline_path)?;
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    input: Option<PathBuf>,
+    history_path: Option<PathBuf>,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let solver = solvers()
+                .into_iter()
+                .find(|solver| solver.day == day)
+                .ok_or_else(|| format!("No solver registered for day {day}"))?;
+            let (_, input) = read_input(day, input)?;
+
+            solver
+                .solve_within(&input, part, None)
+                .map_err(|e| format!("Day {day} part {part}: {e}"))?
+                .answer
+        }
+    };
+
+    let history_path = history_path.unwrap_or_else(|| {
+        inputs::cache_directory()
+            .join(YEAR.to_string())
+            .join("submissions.toml")
+    });
+    let mut history = History::load(&history_path)?;
+
+    history.check(day, part, &answer)?;
+    let session =
+        inputs::session().ok_or_else(|| "Set AOC_SESSION to submit answers".to_string())?;
+    let submitter = HttpSubmitter::new(&inputs::base_url(), &session);
+
+    let outcome = submit::submit(&mut history, &submitter, YEAR, day, part, &answer)?;
+    history.save(&history_path)?;
+
+    match outcome {
+        Outcome::Correct => {
+            println!("Day {day} part {part}: {answer} is correct");
+            Ok(())
+        }
+        outcome => Err(format!("Day {day} part {part}: {answer} is {outcome}")),
+    }
+}
+
+fn check(day: u8, part: Option<u8>, timeout: Option<Duration>) -> Result<(), String> {
+    let solver = solvers()
+        .into_iter()
+        .find(|solver| solver.day == day)
+        .ok_or_else(|| format!("No solver registered for day {day}"))?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let rows = summary::check(&solver, &parts, timeout)?;
+
+    if rows.is_empty() {
+        return Err(format!("Day {day} has no recorded answers to check"));
+    }
+
+    println!("{}", summary::diff(&rows));
+
+    match rows.iter().filter(|row| row.status != Status::Ok).count() {
+        0 => Ok(()),
+        failures => Err(format!("{failures} of {} answers failed", rows.len())),
+    }
+}
+
+// the solvers are compiled in, so each change means rebuilding and checking in a fresh process
+fn watch(day: u8, part: Option<u8>, timeout: Option<Duration>) -> Result<(), String> {
+    if !solvers().iter().any(|solver| solver.day == day) {
+        return Err(format!("No solver registered for day {day}"));
+    }
+
+    let workspace = workspace_directory();
+    let mut watcher = Watcher::new(&[
+        day_directory(day),
+        workspace.join("common").join("src"),
+        workspace.join("grid").join("src"),
+    ])?;
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut arguments = vec!["run".to_string(), "--quiet".to_string()];
+    if !cfg!(debug_assertions) {
+        arguments.push("--release".to_string());
+    }
+    arguments.extend(["-p", "aoc", "--", "check"].map(String::from));
+    arguments.push(day.to_string());
+    if let Some(part) = part {
+        arguments.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(timeout) = timeout {
+        arguments.extend(["--timeout".to_string(), timeout.as_secs_f64().to_string()]);
+    }
+
+    loop {
+        // a failed build or check has already said why
+        let _ = process::Command::new(&cargo)
+            .args(&arguments)
+            .current_dir(&workspace)
+            .status()
+            .map_err(|e| format!("Failed to run cargo: {e}"))?;
+
+        println!("Watching day {day} for changes");
+
+        let changed = watcher.wait()?;
+        let names: Vec<_> = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&workspace)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        println!("\n{} changed", names.join(", "));
+    }
+}
+
+fn repl(day: u8, input: Option<PathBuf>) -> Result<(), String> {
+    let solver = solvers()
+        .into_iter()
+        .find(|solver| solver.day == day)
+        .ok_or_else(|| format!("No solver registered for day {day}"))?;
+
+    let repl = solver
+        .repl
+        .ok_or_else(|| format!("Day {day} has no commands to explore its input with"))?;
+
+    let (path, input) = read_input(day, input)?;
+
+    repl(&input, &mut io::stdin().lock(), &mut io::stdout())
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn new(day: u8) -> Result<(), String> {
+    let workspace = workspace_directory();
+
+    for path in scaffold::new_day(&workspace, day)? {
+        println!(
+            "{}",
+            path.strip_prefix(&workspace).unwrap_or(&path).display()
+        );
+    }
+
+    Ok(())
+}
+
+fn serve(port: u16, no_cache: bool, timeout: Duration) -> Result<(), String> {
+    let server = serve::bind(port)?;
+    let api = Api {
+        solvers: solvers(),
+        cache: (!no_cache).then(ResultCache::from_env),
+        limit: Some(timeout),
+    };
+
+    eprintln!("Listening on http://{}", server.server_addr());
+    serve::serve(&server, &api);
+
+    Ok(())
+}
+
+fn gen(day: u8, seed: u64, size: usize) -> Result<(), String> {
+    let generator = generators()
+        .into_iter()
+        .find(|generator| generator.day == day)
+        .ok_or_else(|| format!("No generator registered for day {day}"))?;
+
+    print!("{}", generator.run(seed, size));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let level = match cli.explain {
+        true => Some(Level::DEBUG),
+        false => cli.trace,
+    };
+    if let Some(level) = level {
+        if let Err(e) = trace::init(level, cli.trace_file.as_deref()) {
+            eprintln!("Failed to start tracing: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let result = match cli.command {
+        Command::Run {
+            all: true,
+            part,
+            no_cache,
+            timeout,
+            ..
+        } => run_all(part, no_cache, timeout),
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+            prefix,
+            format,
+            no_cache,
+            timeout,
+            all: false,
+        } => run(day, part, input, prefix, format, no_cache, timeout),
+        Command::Run { day: None, .. } => unreachable!("clap requires a day without --all"),
+        Command::Bench {
+            days,
+            part,
+            runs,
+            save_baseline,
+            baseline,
+        } => bench(days, part, runs as usize, save_baseline, baseline),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            history,
+        } => submit(day, part, answer, input, history),
+        Command::Gen { day, seed, size } => gen(day, seed, size),
+        Command::Check { day, part, timeout } => check(day, part, timeout),
+        Command::New { day } => new(day),
+        Command::Serve {
+            port,
+            no_cache,
+            timeout,
+        } => serve(port, no_cache, timeout),
+        Command::Repl { day, input } => repl(day, input),
+        Command::Watch { day, part, timeout } => watch(day, part, timeout),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::alloc::AllocationStats;
+use crate::results::Attempt;
+use crate::solvers::Solver;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+// one part solved for one input, as printed by --format json
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub input: String,
+    pub input_sha256: String,
+    pub cached: bool,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocationStats>,
+    pub version: &'static str,
+}
+
+impl Report {
+    pub fn new(solver: &Solver, part: u8, path: &Path, input: &str, attempt: &Attempt) -> Self {
+        let (answer, error, parse_ns, solve_ns) = match &attempt.result {
+            // nothing was timed
+            Ok(solved) if attempt.cached => (Some(solved.answer.clone()), None, None, None),
+            Ok(solved) => (
+                Some(solved.answer.clone()),
+                None,
+                Some(solved.parse_time.as_nanos() as u64),
+                Some(solved.solve_time.as_nanos() as u64),
+            ),
+            Err(e) => (None, Some(e.to_string()), None, None),
+        };
+
+        Self {
+            day: solver.day,
+            part,
+            answer,
+            error,
+            input: path.display().to_string(),
+            input_sha256: input_hash(input),
+            cached: attempt.cached,
+            parse_ns,
+            solve_ns,
+            allocations: attempt.allocations,
+            version: solver.version,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Reports always serialize")
+    }
+}
+
+// of the input as the solvers see it, so line endings and the like don't change it
+pub fn input_hash(input: &str) -> String {
+    let normalized = common::input::normalize(input);
+    let bytes = match &normalized {
+        Ok(normalized) => normalized.as_bytes(),
+        Err(_) => input.as_bytes(),
+    };
+
+    format!("{:x}", Sha256::digest(bytes))
+}
@@ -0,0 +1,100 @@
+use crate::alloc::{self, AllocationStats};
+use crate::inputs::cache_directory;
+use crate::report::input_hash;
+use crate::solvers::Solver;
+use common::{Error, Solved};
+use std::fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+// answers already worked out, kept per day under the build of the solver that found them
+pub struct ResultCache {
+    directory: PathBuf,
+}
+
+impl ResultCache {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(cache_directory().join("results"))
+    }
+
+    pub fn path(&self, solver: &Solver, part: u8, input: &str) -> PathBuf {
+        self.directory
+            .join(format!("day{}", solver.day))
+            .join(solver.build_id)
+            .join(format!("{}-part{part}.txt", input_hash(input)))
+    }
+
+    pub fn get(&self, solver: &Solver, part: u8, input: &str) -> Option<String> {
+        read_to_string(self.path(solver, part, input)).ok()
+    }
+
+    // also drops whatever earlier builds of the day left behind, since they can't be hit again
+    pub fn put(&self, solver: &Solver, part: u8, input: &str, answer: &str) -> Result<(), String> {
+        let path = self.path(solver, part, input);
+        let build_directory = path.parent().expect("Results are always in a directory");
+
+        if let Some(day_directory) = build_directory.parent() {
+            for entry in read_dir(day_directory).into_iter().flatten().flatten() {
+                if entry.path() != build_directory {
+                    remove_dir_all(entry.path())
+                        .map_err(|e| format!("Failed to remove {}: {e}", entry.path().display()))?;
+                }
+            }
+        }
+
+        create_dir_all(build_directory)
+            .map_err(|e| format!("Failed to create {}: {e}", build_directory.display()))?;
+
+        write(&path, answer).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+    }
+}
+
+// how solving one part went
+pub struct Attempt {
+    pub result: Result<Solved, Error>,
+    pub cached: bool,
+    // None if cached, or without the alloc-stats feature
+    pub allocations: Option<AllocationStats>,
+}
+
+// the cached answer if there is one, otherwise solved and cached
+pub fn solve(
+    cache: Option<&ResultCache>,
+    solver: &Solver,
+    input: &str,
+    part: u8,
+    limit: Option<Duration>,
+) -> Attempt {
+    if let Some(answer) = cache.and_then(|cache| cache.get(solver, part, input)) {
+        let solved = Solved {
+            answer,
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+        };
+
+        return Attempt {
+            result: Ok(solved),
+            cached: true,
+            allocations: None,
+        };
+    }
+
+    let (result, allocations) = alloc::measure(|| solver.solve_within(input, part, limit));
+
+    if let (Some(cache), Ok(solved)) = (cache, &result) {
+        // a cache that can't be written only costs time
+        if let Err(e) = cache.put(solver, part, input, &solved.answer) {
+            eprintln!("{e}");
+        }
+    }
+
+    Attempt {
+        result,
+        cached: false,
+        allocations,
+    }
+}
@@ -0,0 +1,202 @@
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const ANSWERS_TOML: &str = include_str!("../templates/answers.toml.in");
+const FUZZ_TARGET: &str = include_str!("../templates/fuzz_target.rs.in");
+const GENERATOR: &str = include_str!("../templates/generate.rs.in");
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    read_to_string(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))
+}
+
+fn create(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(directory) = path.parent() {
+        create_dir_all(directory)
+            .map_err(|e| format!("Failed to create {}: {e}", directory.display()))?;
+    }
+
+    write(path, contents).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+// the offset of the line after the last one starting with prefix
+fn after_last_line(contents: &str, prefix: &str, path: &Path) -> Result<usize, String> {
+    let mut offset = 0;
+    let mut found = None;
+
+    for line in contents.split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with(prefix) {
+            found = Some(offset);
+        }
+    }
+
+    found.ok_or_else(|| format!("Expected a line starting '{prefix}' in {}", path.display()))
+}
+
+fn register_member(workspace: &Path, day: u8) -> Result<PathBuf, String> {
+    let path = workspace.join("Cargo.toml");
+    let mut contents = read(&path)?;
+
+    let offset = after_last_line(&contents, "    \"day", &path)?;
+    contents.insert_str(offset, &format!("    \"day{day}\",\n"));
+
+    create(&path, &contents)?;
+    Ok(path)
+}
+
+fn register_dependency(manifest: &Path, day: u8) -> Result<(), String> {
+    let mut contents = read(manifest)?;
+
+    let offset = after_last_line(&contents, "day", manifest)?;
+    contents.insert_str(
+        offset,
+        &format!("day{day} = {{ path = \"../day{day}\" }}\n"),
+    );
+
+    create(manifest, &contents)
+}
+
+fn register_solver(workspace: &Path, day: u8) -> Result<PathBuf, String> {
+    let path = workspace.join("aoc").join("src").join("solvers.rs");
+    let mut contents = read(&path)?;
+
+    // the last entry in solvers()
+    let offset = after_last_line(&contents, "        },", &path)?;
+    contents.insert_str(
+        offset,
+        &format!(
+            "        Solver {{
+            day: {day},
+            solve: solve::<day{day}::Day{day}>,
+            check: check::<day{day}::Day{day}>,
+            parts: &[],
+            repl: None,
+            version: day{day}::VERSION,
+            build_id: build_ids::DAY{day},
+        }},\n"
+        ),
+    );
+
+    create(&path, &contents)?;
+    Ok(path)
+}
+
+// the offset of the first line at or after from that starts with prefix
+fn next_line(contents: &str, from: usize, prefix: &str, path: &Path) -> Result<usize, String> {
+    let mut offset = 0;
+
+    for line in contents.split_inclusive('\n') {
+        if offset >= from && line.starts_with(prefix) {
+            return Ok(offset);
+        }
+        offset += line.len();
+    }
+
+    Err(format!(
+        "Expected a line starting '{prefix}' in {}",
+        path.display()
+    ))
+}
+
+fn register_generator(workspace: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let directory = workspace.join("aoc").join("src").join("generate");
+    let generator = directory.join(format!("day{day}.rs"));
+    create(&generator, &fill(GENERATOR, day))?;
+
+    let path = directory.join("mod.rs");
+    let mut contents = read(&path)?;
+
+    // the days' modules, in the order rustfmt sorts them into
+    let start = next_line(&contents, 0, "mod day", &path)?;
+    let end = after_last_line(&contents, "mod day", &path)?;
+    let mut modules: Vec<&str> = contents[start..end].lines().collect();
+    let module = format!("mod day{day};");
+    modules.push(&module);
+    modules.sort_by_key(|module| module.trim_end_matches(';'));
+    let modules = format!("{}\n", modules.join("\n"));
+    contents.replace_range(start..end, &modules);
+
+    // the end of the list in generators()
+    let start = contents
+        .find("pub fn generators()")
+        .ok_or_else(|| format!("Expected generators() in {}", path.display()))?;
+    let offset = next_line(&contents, start, "    ]", &path)?;
+    contents.insert_str(
+        offset,
+        &format!(
+            "        Generator {{
+            day: {day},
+            generate: day{day}::generate,
+        }},\n"
+        ),
+    );
+
+    create(&path, &contents)?;
+    Ok(vec![generator, path])
+}
+
+fn register_fuzz_target(workspace: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let manifest = workspace.join("fuzz").join("Cargo.toml");
+    register_dependency(&manifest, day)?;
+
+    let mut contents = read(&manifest)?;
+    contents.push_str(&format!(
+        "\n[[bin]]\nname = \"day{day}\"\npath = \"fuzz_targets/day{day}.rs\"\ntest = false\ndoc = false\nbench = false\n"
+    ));
+    create(&manifest, &contents)?;
+
+    let target = workspace
+        .join("fuzz")
+        .join("fuzz_targets")
+        .join(format!("day{day}.rs"));
+    create(&target, &fill(FUZZ_TARGET, day))?;
+
+    Ok(vec![manifest, target])
+}
+
+// creates a crate for the day from the templates, and registers it with the workspace, the
+// runner, the input generators and the fuzzer, returning every file created or changed
+pub fn new_day(workspace: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!(
+            "There is no day {day}: puzzles run from day 1 to 25"
+        ));
+    }
+
+    let directory = workspace.join(format!("day{day}"));
+    if directory.exists() {
+        return Err(format!("day{day} already exists"));
+    }
+
+    let mut files = Vec::new();
+
+    for (path, template) in [
+        (directory.join("Cargo.toml"), CARGO_TOML),
+        (directory.join("src").join("lib.rs"), LIB_RS),
+        (directory.join("src").join("main.rs"), MAIN_RS),
+        (directory.join("answers.toml"), ANSWERS_TOML),
+        (directory.join("test.txt"), ""),
+    ] {
+        create(&path, &fill(template, day))?;
+        files.push(path);
+    }
+
+    files.push(register_member(workspace, day)?);
+
+    let manifest = workspace.join("aoc").join("Cargo.toml");
+    register_dependency(&manifest, day)?;
+    files.push(manifest);
+
+    files.push(register_solver(workspace, day)?);
+    files.extend(register_generator(workspace, day)?);
+    files.extend(register_fuzz_target(workspace, day)?);
+
+    Ok(files)
+}
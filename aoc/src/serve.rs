@@ -0,0 +1,151 @@
+use crate::report::Report;
+use crate::results::{self, ResultCache};
+use crate::solvers::Solver;
+use common::Error;
+use serde::Serialize;
+use std::io::Read;
+use std::path::Path;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// far larger than any puzzle input
+const MAX_INPUT_BYTES: u64 = 16 * 1024 * 1024;
+
+#[derive(Serialize)]
+struct Day {
+    day: u8,
+    parts: &'static [u8],
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+fn failure(status: u16, error: impl Into<String>) -> (u16, String) {
+    let failure = Failure {
+        error: error.into(),
+    };
+
+    (
+        status,
+        serde_json::to_string(&failure).expect("Failures always serialize"),
+    )
+}
+
+// answers requests for the registered solvers
+pub struct Api {
+    pub solvers: Vec<Solver>,
+    pub cache: Option<ResultCache>,
+    pub limit: Option<Duration>,
+}
+
+impl Api {
+    // the response's status and JSON body
+    pub fn handle(&self, method: &Method, url: &str, body: &[u8]) -> (u16, String) {
+        let path: Vec<&str> = url
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        match (method, path.as_slice()) {
+            (Method::Get, ["days"]) => self.days(),
+            (Method::Post, ["solve", day, part]) => self.solve(day, part, body),
+            (_, ["days"] | ["solve", _, _]) => failure(405, "Method not allowed"),
+            _ => failure(404, format!("No such endpoint: {url}")),
+        }
+    }
+
+    fn days(&self) -> (u16, String) {
+        let days: Vec<Day> = self
+            .solvers
+            .iter()
+            .map(|solver| Day {
+                day: solver.day,
+                parts: solver.parts,
+                version: solver.version,
+            })
+            .collect();
+
+        (
+            200,
+            serde_json::to_string(&days).expect("Days always serialize"),
+        )
+    }
+
+    fn solve(&self, day: &str, part: &str, body: &[u8]) -> (u16, String) {
+        let Some(solver) = day
+            .parse::<u8>()
+            .ok()
+            .and_then(|day| self.solvers.iter().find(|solver| solver.day == day))
+        else {
+            return failure(404, format!("No solver registered for day {day}"));
+        };
+
+        let part = match part.parse::<u8>() {
+            Ok(part @ (1 | 2)) => part,
+            _ => return failure(404, format!("There is no part {part}")),
+        };
+
+        let Ok(input) = std::str::from_utf8(body) else {
+            return failure(400, "The input isn't UTF-8");
+        };
+
+        let attempt = results::solve(self.cache.as_ref(), solver, input, part, self.limit);
+
+        let status = match &attempt.result {
+            Ok(_) => 200,
+            Err(Error::Parse { .. } | Error::Io(_)) => 400,
+            Err(Error::Unimplemented) => 501,
+            Err(Error::TimedOut(_)) => 504,
+            Err(_) => 422,
+        };
+
+        let report = Report::new(solver, part, Path::new("request"), input, &attempt);
+
+        (status, report.to_json())
+    }
+}
+
+fn respond(api: &Api, mut request: Request) -> Result<(), String> {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT_BYTES + 1)
+        .read_to_end(&mut body);
+
+    let (status, json) = match read {
+        Ok(_) if body.len() as u64 > MAX_INPUT_BYTES => failure(413, "The input is too large"),
+        Ok(_) => api.handle(request.method(), request.url(), &body),
+        Err(e) => failure(400, format!("Failed to read the request: {e}")),
+    };
+
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json").expect("The header is valid");
+    let response = Response::from_string(json)
+        .with_status_code(status)
+        .with_header(content_type);
+
+    request
+        .respond(response)
+        .map_err(|e| format!("Failed to respond: {e}"))
+}
+
+// only on the loopback interface: the solvers aren't meant to face the network
+pub fn bind(port: u16) -> Result<Server, String> {
+    Server::http(("127.0.0.1", port)).map_err(|e| format!("Failed to listen on port {port}: {e}"))
+}
+
+// answers requests one at a time until the server is unblocked
+pub fn serve(server: &Server, api: &Api) {
+    for request in server.incoming_requests() {
+        // a client that hung up doesn't affect the next
+        if let Err(e) = respond(api, request) {
+            eprintln!("{e}");
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use common::{cancel, check, repl, solve, Error, Solved};
+use std::time::Duration;
+
+mod build_ids {
+    include!(concat!(env!("OUT_DIR"), "/build_ids.rs"));
+}
+
+pub struct Solver {
+    pub day: u8,
+    pub solve: fn(&str, u8) -> Result<Solved, Error>,
+    pub check: fn(&str) -> Result<(), Error>,
+    // the parts it solves, rather than answering Error::Unimplemented
+    pub parts: &'static [u8],
+    // explores the parsed input with commands read from the reader, for days that offer any
+    pub repl: Option<repl::Runner>,
+    pub version: &'static str,
+    // changes whenever the day or the crates it shares are edited
+    pub build_id: &'static str,
+}
+
+impl Solver {
+    // always on a thread of its own, for the stack room. A limit is enforced by the solver's
+    // checkpoints.
+    pub fn solve_within(
+        &self,
+        input: &str,
+        part: u8,
+        limit: Option<Duration>,
+    ) -> Result<Solved, Error> {
+        let span = tracing::debug_span!("day", day = self.day);
+
+        let Some(limit) = limit else {
+            return cancel::on_solver_thread(|| span.in_scope(|| (self.solve)(input, part)))?;
+        };
+
+        let solve = self.solve;
+        let input = input.to_string();
+
+        cancel::with_timeout(limit, move || span.in_scope(|| solve(&input, part)))
+    }
+}
+
+pub fn solvers() -> Vec<Solver> {
+    vec![
+        Solver {
+            day: 1,
+            solve: solve::<day1::Day1>,
+            check: check::<day1::Day1>,
+            parts: &[1, 2],
+            repl: None,
+            version: day1::VERSION,
+            build_id: build_ids::DAY1,
+        },
+        Solver {
+            day: 2,
+            solve: solve::<day2::Day2>,
+            check: check::<day2::Day2>,
+            parts: &[1, 2],
+            repl: None,
+            version: day2::VERSION,
+            build_id: build_ids::DAY2,
+        },
+        Solver {
+            day: 3,
+            solve: solve::<day3::Day3>,
+            check: check::<day3::Day3>,
+            parts: &[1, 2],
+            repl: None,
+            version: day3::VERSION,
+            build_id: build_ids::DAY3,
+        },
+        Solver {
+            day: 4,
+            solve: solve::<day4::Day4>,
+            check: check::<day4::Day4>,
+            parts: &[1, 2],
+            repl: None,
+            version: day4::VERSION,
+            build_id: build_ids::DAY4,
+        },
+        Solver {
+            day: 5,
+            solve: solve::<day5::Day5>,
+            check: check::<day5::Day5>,
+            parts: &[1, 2],
+            repl: Some(repl::run::<day5::Day5>),
+            version: day5::VERSION,
+            build_id: build_ids::DAY5,
+        },
+        Solver {
+            day: 6,
+            solve: solve::<day6::Day6>,
+            check: check::<day6::Day6>,
+            parts: &[1, 2],
+            repl: None,
+            version: day6::VERSION,
+            build_id: build_ids::DAY6,
+        },
+        Solver {
+            day: 7,
+            solve: solve::<day7::Day7>,
+            check: check::<day7::Day7>,
+            parts: &[1, 2],
+            repl: None,
+            version: day7::VERSION,
+            build_id: build_ids::DAY7,
+        },
+        Solver {
+            day: 8,
+            solve: solve::<day8::Day8>,
+            check: check::<day8::Day8>,
+            parts: &[1, 2],
+            repl: Some(repl::run::<day8::Day8>),
+            version: day8::VERSION,
+            build_id: build_ids::DAY8,
+        },
+        Solver {
+            day: 9,
+            solve: solve::<day9::Day9>,
+            check: check::<day9::Day9>,
+            parts: &[1, 2],
+            repl: None,
+            version: day9::VERSION,
+            build_id: build_ids::DAY9,
+        },
+        Solver {
+            day: 10,
+            solve: solve::<day10::Day10>,
+            check: check::<day10::Day10>,
+            parts: &[1, 2],
+            repl: None,
+            version: day10::VERSION,
+            build_id: build_ids::DAY10,
+        },
+        Solver {
+            day: 11,
+            solve: solve::<day11::Day11>,
+            check: check::<day11::Day11>,
+            parts: &[1, 2],
+            repl: None,
+            version: day11::VERSION,
+            build_id: build_ids::DAY11,
+        },
+        Solver {
+            day: 12,
+            solve: solve::<day12::Day12>,
+            check: check::<day12::Day12>,
+            parts: &[1, 2],
+            repl: None,
+            version: day12::VERSION,
+            build_id: build_ids::DAY12,
+        },
+        Solver {
+            day: 13,
+            solve: solve::<day13::Day13>,
+            check: check::<day13::Day13>,
+            parts: &[1, 2],
+            repl: None,
+            version: day13::VERSION,
+            build_id: build_ids::DAY13,
+        },
+        Solver {
+            day: 14,
+            solve: solve::<day14::Day14>,
+            check: check::<day14::Day14>,
+            parts: &[1, 2],
+            repl: Some(repl::run::<day14::Day14>),
+            version: day14::VERSION,
+            build_id: build_ids::DAY14,
+        },
+        Solver {
+            day: 15,
+            solve: solve::<day15::Day15>,
+            check: check::<day15::Day15>,
+            parts: &[1, 2],
+            repl: Some(repl::run::<day15::Day15>),
+            version: day15::VERSION,
+            build_id: build_ids::DAY15,
+        },
+        Solver {
+            day: 16,
+            solve: solve::<day16::Day16>,
+            check: check::<day16::Day16>,
+            parts: &[1, 2],
+            repl: None,
+            version: day16::VERSION,
+            build_id: build_ids::DAY16,
+        },
+        Solver {
+            day: 17,
+            solve: solve::<day17::Day17>,
+            check: check::<day17::Day17>,
+            parts: &[1, 2],
+            repl: None,
+            version: day17::VERSION,
+            build_id: build_ids::DAY17,
+        },
+        Solver {
+            day: 18,
+            solve: solve::<day18::Day18>,
+            check: check::<day18::Day18>,
+            parts: &[1, 2],
+            repl: None,
+            version: day18::VERSION,
+            build_id: build_ids::DAY18,
+        },
+    ]
+}
@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub submitted_at: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = match read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+        };
+
+        toml::from_str(&contents).map_err(|e| format!("Failed to parse {}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            create_dir_all(directory)
+                .map_err(|e| format!("Failed to create {}: {e}", directory.display()))?;
+        }
+
+        let contents =
+            toml::to_string(self).map_err(|e| format!("Failed to write history: {e}"))?;
+
+        write(path, contents).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+    }
+
+    fn submissions(&self, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day && submission.part == part)
+    }
+
+    // why an answer isn't worth submitting, judging by earlier outcomes
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        if let Some(solved) = self
+            .submissions(day, part)
+            .find(|submission| submission.outcome == Outcome::Correct)
+        {
+            return Err(format!(
+                "Day {day} part {part} was already solved with {}",
+                solved.answer
+            ));
+        }
+
+        if let Some(previous) = self
+            .submissions(day, part)
+            .find(|submission| submission.answer == answer)
+        {
+            return Err(format!(
+                "{answer} was already submitted for day {day} part {part}, and was {}",
+                previous.outcome
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+
+        for submission in self.submissions(day, part) {
+            let Ok(bound) = submission.answer.parse::<i64>() else {
+                continue;
+            };
+
+            match submission.outcome {
+                Outcome::TooHigh if value >= bound => {
+                    return Err(format!("{answer} can't be right: {bound} was too high"))
+                }
+                Outcome::TooLow if value <= bound => {
+                    return Err(format!("{answer} can't be right: {bound} was too low"))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, outcome: Outcome) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at,
+        });
+    }
+}
+
+pub trait Submitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, String>;
+}
+
+// posts answers as the account the session cookie belongs to
+pub struct HttpSubmitter {
+    base_url: String,
+    session: String,
+}
+
+impl HttpSubmitter {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "aoc2023 answer submission")
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| format!("Failed to submit to {url}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("Failed to read {url}: {e}"))?;
+
+        read_outcome(&response)
+    }
+}
+
+// the site answers with a page of prose rather than a status
+fn read_outcome(response: &str) -> Result<Outcome, String> {
+    if response.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if response.contains("your answer is too high") {
+        Ok(Outcome::TooHigh)
+    } else if response.contains("your answer is too low") {
+        Ok(Outcome::TooLow)
+    } else if response.contains("That's not the right answer") {
+        Ok(Outcome::Wrong)
+    } else if response.contains("You gave an answer too recently") {
+        Err("Answer not submitted: rate limited, try again later".to_string())
+    } else if response.contains("Did you already complete it") {
+        Err("Answer not submitted: this part is already complete or still locked".to_string())
+    } else {
+        Err("Answer not submitted: unrecognised response".to_string())
+    }
+}
+
+// submits an answer the history doesn't already rule out, and records how it went
+pub fn submit(
+    history: &mut History,
+    submitter: &dyn Submitter,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, String> {
+    history.check(day, part, answer)?;
+
+    let outcome = submitter.submit(year, day, part, answer)?;
+    history.record(day, part, answer, outcome);
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_uses_bounds_and_past_answers() {
+        let mut history = History::default();
+        history.record(1, 1, "100", Outcome::TooHigh);
+        history.record(1, 1, "20", Outcome::TooLow);
+        history.record(1, 1, "50", Outcome::Wrong);
+
+        assert!(history.check(1, 1, "100").is_err());
+        assert!(history.check(1, 1, "150").is_err());
+        assert!(history.check(1, 1, "10").is_err());
+        assert!(history.check(1, 1, "50").is_err());
+        assert!(history.check(1, 1, "60").is_ok());
+        assert!(history.check(1, 2, "150").is_ok());
+
+        history.record(1, 1, "60", Outcome::Correct);
+        assert!(history.check(1, 1, "61").is_err());
+    }
+}
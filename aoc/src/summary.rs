@@ -0,0 +1,233 @@
+use crate::answers::{self, Expected};
+use crate::bench::format_duration;
+use crate::inputs::InputCache;
+use crate::results::{self, ResultCache};
+use crate::solvers::Solver;
+use crate::{day_directory, YEAR};
+use common::{Error, STACK_SIZE};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use std::fmt::{self, Display, Formatter, Write};
+use std::fs::read_to_string;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Ok,
+    Error(String),
+    Timeout,
+    Mismatch { expected: String },
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Error(message) => write!(f, "error: {message}"),
+            Status::Timeout => write!(f, "timeout"),
+            Status::Mismatch { expected } => write!(f, "mismatch: expected {expected}"),
+        }
+    }
+}
+
+// one part of one day's input
+#[derive(Debug)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    // None if the answer came from the cache
+    pub duration: Option<Duration>,
+    pub status: Status,
+}
+
+struct Job<'a> {
+    solver: &'a Solver,
+    part: u8,
+    label: String,
+    // the input and any answers recorded for it
+    input: Result<(String, Option<Expected>), String>,
+}
+
+// solves each part of each day's cached input concurrently, in order of day and part
+pub fn run_all(
+    solvers: &[Solver],
+    parts: &[u8],
+    inputs: &InputCache,
+    cache: Option<&ResultCache>,
+    limit: Option<Duration>,
+) -> Result<Vec<Row>, String> {
+    // fetching stays sequential, to go easy on the puzzle site
+    let mut jobs = Vec::new();
+    for solver in solvers {
+        let label = inputs.path(YEAR, solver.day).display().to_string();
+        let input = inputs.load(YEAR, solver.day).and_then(|(_, input)| {
+            let expected = answers::find(solver.day, &input)?;
+            Ok((input, expected))
+        });
+
+        for &part in parts {
+            jobs.push(Job {
+                solver,
+                part,
+                label: label.clone(),
+                input: input.clone(),
+            });
+        }
+    }
+
+    run_jobs(&jobs, cache, limit)
+}
+
+// solves every recorded part of each input in the day's answers.toml, labelled with the file
+pub fn check(solver: &Solver, parts: &[u8], limit: Option<Duration>) -> Result<Vec<Row>, String> {
+    let mut jobs = Vec::new();
+
+    for (file, expected) in answers::load(solver.day)? {
+        let path = day_directory(solver.day).join(&file);
+        let input =
+            read_to_string(&path).map_err(|e| format!("Failed to read {}: {e}", path.display()));
+
+        for &part in parts {
+            if expected.part(part).is_some() {
+                jobs.push(Job {
+                    solver,
+                    part,
+                    label: file.clone(),
+                    input: input.clone().map(|input| (input, Some(expected.clone()))),
+                });
+            }
+        }
+    }
+
+    run_jobs(&jobs, None, limit)
+}
+
+fn run_jobs(
+    jobs: &[Job],
+    cache: Option<&ResultCache>,
+    limit: Option<Duration>,
+) -> Result<Vec<Row>, String> {
+    // allocation counts are process wide, so measured solves can't overlap: one thread, else as
+    // many as there are cores
+    let threads = if cfg!(feature = "alloc-stats") { 1 } else { 0 };
+
+    let pool = ThreadPoolBuilder::new()
+        .stack_size(STACK_SIZE)
+        .num_threads(threads)
+        .build()
+        .map_err(|e| format!("Failed to start solver threads: {e}"))?;
+
+    let rows: Vec<Option<Row>> = pool.install(|| {
+        jobs.par_iter()
+            .map(|job| run_job(job, cache, limit))
+            .collect()
+    });
+
+    Ok(rows.into_iter().flatten().collect())
+}
+
+// None for parts that haven't been written
+fn run_job(job: &Job, cache: Option<&ResultCache>, limit: Option<Duration>) -> Option<Row> {
+    let row = |answer, duration, status| Row {
+        day: job.solver.day,
+        part: job.part,
+        input: job.label.clone(),
+        answer,
+        duration,
+        status,
+    };
+
+    let (input, expected) = match &job.input {
+        Ok(input) => input,
+        Err(e) => return Some(row(None, None, Status::Error(e.clone()))),
+    };
+
+    let attempt = results::solve(cache, job.solver, input, job.part, limit);
+
+    let solved = match attempt.result {
+        Ok(solved) => solved,
+        Err(Error::Unimplemented) => return None,
+        Err(Error::TimedOut(limit)) => return Some(row(None, Some(limit), Status::Timeout)),
+        Err(e) => return Some(row(None, None, Status::Error(e.to_string()))),
+    };
+
+    let status = match expected
+        .as_ref()
+        .and_then(|expected| expected.part(job.part))
+    {
+        Some(expected) if expected.to_string() != solved.answer => Status::Mismatch {
+            expected: expected.to_string(),
+        },
+        _ => Status::Ok,
+    };
+    let duration = (!attempt.cached).then_some(solved.parse_time + solved.solve_time);
+
+    Some(row(Some(solved.answer), duration, status))
+}
+
+pub fn table(rows: &[Row]) -> String {
+    let answer_width = rows
+        .iter()
+        .filter_map(|row| row.answer.as_ref().map(String::len))
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "{:>3} {:>4}  {:<answer_width$}  {:>10}  status",
+        "day", "part", "answer", "duration"
+    );
+
+    for row in rows {
+        let duration = match row.duration {
+            Some(duration) => format_duration(duration),
+            None if row.answer.is_some() => "cached".to_string(),
+            None => "-".to_string(),
+        };
+
+        let _ = write!(
+            table,
+            "\n{:>3} {:>4}  {:<answer_width$}  {:>10}  {}",
+            row.day,
+            row.part,
+            row.answer.as_deref().unwrap_or("-"),
+            duration,
+            row.status
+        );
+    }
+
+    table
+}
+
+// a line per recorded answer, saying whether it still comes out the same
+pub fn diff(rows: &[Row]) -> String {
+    let input_width = rows
+        .iter()
+        .map(|row| row.input.len())
+        .max()
+        .unwrap_or_default();
+
+    rows.iter()
+        .map(|row| {
+            let mark = match row.status {
+                Status::Ok => "pass",
+                _ => "FAIL",
+            };
+            let mut line = format!(
+                "{mark}  {:<input_width$}  part {}  {}",
+                row.input,
+                row.part,
+                row.answer.as_deref().unwrap_or("-")
+            );
+
+            if row.status != Status::Ok {
+                let _ = write!(line, "  {}", row.status);
+            }
+
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,140 @@
+use inotify::{EventMask, Events, Inotify, WatchDescriptor, WatchMask};
+use std::collections::HashMap;
+use std::fs::read_dir;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+// editors tend to write a file in several steps, which should only count as one change
+const SETTLE: Duration = Duration::from_millis(200);
+
+// sources, inputs and recorded answers, rather than editors' swap and backup files
+fn relevant(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("rs" | "txt" | "toml")
+    )
+}
+
+pub struct Watcher {
+    inotify: Inotify,
+    directories: HashMap<WatchDescriptor, PathBuf>,
+}
+
+impl Watcher {
+    // watches each directory and those below it, apart from build output, including directories
+    // made later
+    pub fn new(directories: &[PathBuf]) -> Result<Self, String> {
+        let inotify = Inotify::init().map_err(|e| format!("Failed to start watching: {e}"))?;
+        let mut watcher = Self {
+            inotify,
+            directories: HashMap::new(),
+        };
+
+        for directory in directories {
+            watcher.add(directory)?;
+        }
+
+        Ok(watcher)
+    }
+
+    // returns the relevant files already there
+    fn add(&mut self, directory: &Path) -> Result<Vec<PathBuf>, String> {
+        let mask = WatchMask::CLOSE_WRITE
+            | WatchMask::CREATE
+            | WatchMask::DELETE
+            | WatchMask::MODIFY
+            | WatchMask::MOVED_TO;
+
+        let descriptor = self
+            .inotify
+            .watches()
+            .add(directory, mask)
+            .map_err(|e| format!("Failed to watch {}: {e}", directory.display()))?;
+        self.directories.insert(descriptor, directory.to_path_buf());
+
+        let entries = read_dir(directory)
+            .map_err(|e| format!("Failed to read {}: {e}", directory.display()))?;
+
+        let mut files = Vec::new();
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() && entry.file_name() != "target" {
+                files.extend(self.add(&path)?);
+            } else if relevant(&path) {
+                files.push(path);
+            }
+        }
+
+        Ok(files)
+    }
+
+    // blocks until a relevant file changes, then gives things time to settle, returning every
+    // file that changed meanwhile
+    pub fn wait(&mut self) -> Result<Vec<PathBuf>, String> {
+        let mut buffer = [0; 4096];
+        let mut changed = Vec::new();
+
+        while changed.is_empty() {
+            let events = self
+                .inotify
+                .read_events_blocking(&mut buffer)
+                .map_err(|e| format!("Failed to watch for changes: {e}"))?;
+            changed.extend(self.changed_files(events));
+        }
+
+        thread::sleep(SETTLE);
+
+        loop {
+            match self.inotify.read_events(&mut buffer) {
+                Ok(events) => changed.extend(self.changed_files(events)),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => return Err(format!("Failed to watch for changes: {e}")),
+            }
+        }
+
+        changed.sort();
+        changed.dedup();
+
+        Ok(changed)
+    }
+
+    // a new directory is watched from then on, and whatever was written to it before the watch
+    // began counts as changed
+    fn changed_files(&mut self, events: Events) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+
+        for event in events {
+            // the directory was deleted, taking its watch with it
+            if event.mask.contains(EventMask::IGNORED) {
+                self.directories.remove(&event.wd);
+                continue;
+            }
+
+            let (Some(directory), Some(name)) = (self.directories.get(&event.wd), event.name)
+            else {
+                continue;
+            };
+            let path = directory.join(name);
+
+            if !event.mask.contains(EventMask::ISDIR) {
+                if relevant(&path) {
+                    changed.push(path);
+                }
+            } else if event
+                .mask
+                .intersects(EventMask::CREATE | EventMask::MOVED_TO)
+                && name != "target"
+            {
+                // it may already be gone again, which is no reason to stop watching the rest
+                if let Ok(files) = self.add(&path) {
+                    changed.extend(files);
+                }
+            }
+        }
+
+        changed
+    }
+}
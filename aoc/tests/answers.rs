@@ -0,0 +1,81 @@
+use aoc::answers;
+use aoc::day_directory;
+use aoc::solvers::solvers;
+use common::STACK_SIZE;
+use std::fs::read_to_string;
+use std::thread;
+
+// runs every solver against every recorded input accepted by the filter, collecting mismatches
+fn check_answers(include: impl Fn(&str) -> bool, mangle: fn(&str) -> String) {
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+
+    for solver in solvers() {
+        let manifest = answers::load(solver.day).expect("Failed to load answers");
+
+        for (file, expected) in manifest.iter().filter(|(file, _)| include(file)) {
+            let input = mangle(
+                &read_to_string(day_directory(solver.day).join(file))
+                    .expect("Failed to read recorded input"),
+            );
+
+            for part in [1, 2] {
+                let Some(answer) = expected.part(part) else {
+                    continue;
+                };
+
+                let description = format!("day {} part {part} {file}", solver.day);
+
+                checked += 1;
+
+                let solve = solver.solve;
+                let input = input.clone();
+                let result = thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn(move || solve(&input, part).map(|solved| solved.answer))
+                    .expect("Failed to spawn solver thread")
+                    .join();
+
+                match result {
+                    Ok(Ok(actual)) if actual == answer.to_string() => {}
+                    Ok(Ok(actual)) => {
+                        mismatches.push(format!("{description}: expected {answer}, got {actual}"))
+                    }
+                    Ok(Err(e)) => mismatches.push(format!("{description}: {e}")),
+                    Err(_) => mismatches.push(format!("{description}: panicked")),
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0, "No recorded answers found");
+    assert!(
+        mismatches.is_empty(),
+        "{} of {checked} answers did not match:\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+}
+
+// as if saved by a Windows editor: a byte order mark, CRLF line endings and stray whitespace
+fn windows_formatting(input: &str) -> String {
+    let lines: Vec<String> = input.lines().map(|line| format!("{line}  ")).collect();
+
+    format!("\u{feff}{}\r\n\r\n", lines.join("\r\n"))
+}
+
+#[test]
+fn sample_answers() {
+    check_answers(|file| file != "input.txt", str::to_string);
+}
+
+#[test]
+fn sample_answers_with_windows_formatting() {
+    check_answers(|file| file != "input.txt", windows_formatting);
+}
+
+#[test]
+#[ignore = "solves every real input, which is slow outside of release builds"]
+fn input_answers() {
+    check_answers(|file| file == "input.txt", str::to_string);
+}
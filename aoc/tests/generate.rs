@@ -0,0 +1,81 @@
+use aoc::generate::generators;
+use aoc::solvers::solvers;
+use common::Error;
+
+#[test]
+fn generated_inputs_solve() {
+    let solvers = solvers();
+    let mut failures = Vec::new();
+
+    for generator in generators() {
+        let solver = solvers
+            .iter()
+            .find(|solver| solver.day == generator.day)
+            .unwrap();
+
+        for size in [1, 3, 10] {
+            for seed in 0..5 {
+                let input = generator.run(seed, size);
+
+                for part in [1, 2] {
+                    match (solver.solve)(&input, part) {
+                        // a day that's only been scaffolded
+                        Ok(_) | Err(Error::Unimplemented) => {}
+                        Err(e) => failures.push(format!(
+                            "Day {} part {part}, seed {seed}, size {size}: {e}\n{input}",
+                            generator.day
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+// as many cards as a real input: the copies won multiply with every card, so too many matches
+// overflow long before this
+#[test]
+fn realistic_scratchcards_solve() {
+    let generator = generators()
+        .into_iter()
+        .find(|generator| generator.day == 4)
+        .unwrap();
+    let solver = solvers()
+        .into_iter()
+        .find(|solver| solver.day == 4)
+        .unwrap();
+
+    for seed in 0..10 {
+        let input = generator.run(seed, 200);
+
+        for part in [1, 2] {
+            if let Err(e) = (solver.solve)(&input, part) {
+                panic!("Part {part}, seed {seed}: {e}");
+            }
+        }
+    }
+}
+
+// so the generated inputs exercise every day, scaffolded ones included
+#[test]
+fn every_day_has_a_generator() {
+    let days: Vec<u8> = generators().iter().map(|generator| generator.day).collect();
+
+    for solver in solvers() {
+        assert!(
+            days.contains(&solver.day),
+            "Day {} has no generator",
+            solver.day
+        );
+    }
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for generator in generators() {
+        assert_eq!(generator.run(7, 8), generator.run(7, 8));
+        assert_ne!(generator.run(7, 8), generator.run(8, 8));
+    }
+}
@@ -0,0 +1,92 @@
+use aoc::inputs::{HttpFetcher, InputCache};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+// a stand-in for the puzzle site: serves day 1 to the right session and 404s everything else
+fn serve(session: &'static str) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            let mut reader = BufReader::new(&stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut authorised = false;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if header.trim() == format!("Cookie: session={session}") {
+                    authorised = true;
+                }
+            }
+
+            let (status, body) = match (request_line.split(' ').nth(1), authorised) {
+                (_, false) => ("400 Bad Request", "Puzzle inputs differ by user."),
+                (Some("/2023/day/1/input"), true) => ("200 OK", "1abc2\npqr3stu8vwx\n"),
+                _ => ("404 Not Found", "Not found"),
+            };
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+#[test]
+fn fetches_once_then_serves_from_cache() {
+    let (base_url, requests) = serve("secret");
+    let directory = tempfile::tempdir().unwrap();
+    let cache = InputCache::new(
+        directory.path().to_path_buf(),
+        Box::new(HttpFetcher::new(&base_url, "secret")),
+    );
+
+    let (path, input) = cache.load(2023, 1).unwrap();
+    assert_eq!(path, directory.path().join("2023").join("day1.txt"));
+    assert_eq!(input, "1abc2\npqr3stu8vwx\n");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), input);
+
+    assert_eq!(cache.load(2023, 1).unwrap().1, input);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn failed_fetches_are_not_cached() {
+    let (base_url, _) = serve("secret");
+    let directory = tempfile::tempdir().unwrap();
+
+    let cache = InputCache::new(
+        directory.path().to_path_buf(),
+        Box::new(HttpFetcher::new(&base_url, "secret")),
+    );
+    let error = cache.load(2023, 2).unwrap_err();
+    assert!(error.contains("no input yet"), "{error}");
+    assert!(!cache.path(2023, 2).exists());
+
+    let cache = InputCache::new(
+        directory.path().to_path_buf(),
+        Box::new(HttpFetcher::new(&base_url, "wrong")),
+    );
+    let error = cache.load(2023, 1).unwrap_err();
+    assert!(error.contains("AOC_SESSION"), "{error}");
+    assert!(!cache.path(2023, 1).exists());
+}
@@ -0,0 +1,191 @@
+use aoc::generate::generators;
+use aoc::solvers::solvers;
+use aoc::workspace_directory;
+use common::Error;
+use day14::Dish;
+use day16::contraption::Contraption;
+use day18::Instruction;
+use day7::Hand;
+use proptest::prelude::*;
+use std::fs::{read_dir, read_to_string};
+use std::time::Duration;
+
+// bytes the puzzle inputs are made of, so arbitrary input gets past the first check more often
+const PUZZLE_TEXT: &str = "[ -~\n]{0,200}";
+
+fn check_all(input: &str) {
+    for solver in solvers() {
+        let _ = (solver.check)(input);
+    }
+}
+
+// a generated input with a few characters swapped for others
+fn mutated_input() -> impl Strategy<Value = (u8, String)> {
+    let days: Vec<u8> = generators().iter().map(|generator| generator.day).collect();
+
+    (prop::sample::select(days), any::<u64>(), 1..12_usize).prop_flat_map(|(day, seed, size)| {
+        let generator = generators()
+            .into_iter()
+            .find(|generator| generator.day == day)
+            .unwrap();
+        let input: Vec<char> = generator.run(seed, size).chars().collect();
+        let length = input.len();
+
+        prop::collection::vec((0..length, any::<char>()), 0..4).prop_map(move |edits| {
+            let mut input = input.clone();
+            for (index, replacement) in edits {
+                input[index] = replacement;
+            }
+            (day, input.into_iter().collect())
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn parsers_never_panic_on_arbitrary_strings(input in any::<String>()) {
+        check_all(&input);
+    }
+
+    #[test]
+    fn parsers_never_panic_on_puzzle_text(input in PUZZLE_TEXT) {
+        check_all(&input);
+    }
+
+    #[test]
+    fn solvers_never_panic_on_mutated_inputs((day, input) in mutated_input()) {
+        let solver = solvers().into_iter().find(|solver| solver.day == day).unwrap();
+        if (solver.check)(&input).is_err() {
+            return Ok(());
+        }
+
+        // a panic on the solver's thread comes back as this rather than failing the test itself
+        for part in [1, 2] {
+            let result = solver.solve_within(&input, part, Some(Duration::from_secs(2)));
+            prop_assert!(
+                !matches!(&result, Err(Error::InvalidState(message)) if message == "The solver panicked"),
+                "day {} part {} panicked on {:?}",
+                day,
+                part,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn hands_round_trip(hand in "[AKQJT98765432]{5}") {
+        let parsed = Hand::try_from(hand.as_str()).unwrap();
+
+        prop_assert_eq!(parsed.to_string(), hand);
+        prop_assert_eq!(Hand::try_from(parsed.to_string().as_str()).unwrap(), parsed);
+    }
+
+    #[test]
+    fn dishes_round_trip(rows in prop::collection::vec("[#O.]{6}", 1..8)) {
+        let input = rows.join("\n");
+
+        prop_assert_eq!(Dish::new(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn contraptions_round_trip(rows in prop::collection::vec(r"[./\\|-]{6}", 1..8)) {
+        let input = rows.join("\n");
+
+        prop_assert_eq!(Contraption::new(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn instructions_round_trip(direction in "[UDLR]", distance in 1..1_000_000_i64) {
+        let instruction = format!("{direction} {distance}");
+
+        prop_assert_eq!(instruction.parse::<Instruction>().unwrap().to_string(), instruction);
+    }
+}
+
+// inputs that once crashed a parser, kept under fuzz/regressions/dayN
+#[test]
+fn fuzz_regressions_parse_without_panicking() {
+    for solver in solvers() {
+        let directory = workspace_directory()
+            .join("fuzz")
+            .join("regressions")
+            .join(format!("day{}", solver.day));
+
+        let Ok(entries) = read_dir(&directory) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let input = read_to_string(&path).unwrap();
+
+            assert!((solver.check)(&input).is_err(), "{} parsed", path.display());
+        }
+    }
+}
+
+fn solve(day: u8, part: u8, input: &str) -> Result<String, Error> {
+    let solver = solvers()
+        .into_iter()
+        .find(|solver| solver.day == day)
+        .unwrap();
+
+    solver
+        .solve_within(input, part, Some(Duration::from_secs(60)))
+        .map(|solved| solved.answer)
+}
+
+// well-formed inputs whose answers are too big for the days' types, and too rare for the
+// mutations above to come up with
+#[test]
+fn overflows_are_errors_not_panics() {
+    let all_unknown = format!("{} 1,1,1", "?".repeat(128));
+    let cases = [
+        (2, 2, "Game 1: 4294967296 red, 4294967296 green, 1 blue\n"),
+        (
+            2,
+            2,
+            concat!(
+                "Game 1: 4294967296 red, 4294967295 green, 1 blue\n",
+                "Game 2: 4294967296 red, 4294967295 green, 1 blue\n"
+            ),
+        ),
+        (3, 1, "18446744073709551615*2\n"),
+        (3, 2, "4294967296*4294967296\n"),
+        (
+            6,
+            1,
+            "Time: 5 4000000000 4000000000 4000000000\nDistance: 1 1 1 1\n",
+        ),
+        (6, 2, "Time: 5\nDistance: 100\n"),
+        (12, 2, all_unknown.as_str()),
+        (18, 1, "R 9223372036854775807 (#70c710)\nR 1 (#70c710)\n"),
+        (
+            18,
+            1,
+            concat!(
+                "R 3037000500 (#70c710)\n",
+                "D 3037000500 (#70c710)\n",
+                "L 3037000500 (#70c710)\n",
+                "U 3037000500 (#70c710)\n"
+            ),
+        ),
+    ];
+
+    for (day, part, input) in cases {
+        let result = solve(day, part, input);
+        assert!(
+            matches!(&result, Err(Error::InvalidState(message)) if message != "The solver panicked"),
+            "day {day} part {part}: {result:?}"
+        );
+    }
+
+    assert_eq!(
+        solve(6, 1, "Time: 5000000000\nDistance: 1\n").unwrap(),
+        "4999999999"
+    );
+    assert!(matches!(
+        solve(18, 1, "R -5 (#70c710)\n"),
+        Err(Error::Parse { .. })
+    ));
+}
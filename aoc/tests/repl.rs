@@ -0,0 +1,67 @@
+use aoc::day_directory;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// what aoc repl prints for the commands, exploring one of the day's samples
+fn session(day: u8, file: &str, commands: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["repl", &day.to_string(), "--input"])
+        .arg(day_directory(day).join(file))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(commands.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn explores_a_parsed_input() {
+    assert_eq!(
+        session(15, "test.txt", "box 3\nbox 256\nquit\n"),
+        "> Box 3: [ot 7] [ab 5] [pc 6]\n> error: There is no box 256\n> "
+    );
+}
+
+#[test]
+fn walks_are_limited() {
+    assert_eq!(
+        session(8, "test1.txt", "walk AAA 3\nwalk AAA 10001\nquit\n"),
+        "> AAA CCC ZZZ ZZZ\n> error: Expected at most 10000 steps, found 10001\n> "
+    );
+}
+
+#[test]
+fn seeds_are_followed_through_every_map() {
+    assert_eq!(
+        session(5, "test.txt", "map 79\nquit\n"),
+        concat!(
+            "> seed 79 -> soil 81 -> fertilizer 81 -> water 81 -> light 74 -> temperature 78 ",
+            "-> humidity 78 -> location 82\n> "
+        )
+    );
+}
+
+#[test]
+fn spin_cycles_are_stepped_and_limited() {
+    assert_eq!(
+        session(14, "test.txt", "step\nstep 2\nshow\nstep 10001\nquit\n"),
+        concat!(
+            "> cycle 1: load 87\n",
+            "> cycle 2: load 69\ncycle 3: load 69\n",
+            "> .....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n",
+            ".O#...O#.#\n....O#...O\n.......OOO\n#...O###.O\n#.OOO#...O\n",
+            "after 3 cycles, load 69\n",
+            "> error: Expected at most 10000 cycles, found 10001\n> "
+        )
+    );
+}
@@ -0,0 +1,70 @@
+use aoc::results::ResultCache;
+use aoc::solvers::{solvers, Solver};
+
+fn solver(day: u8) -> Solver {
+    solvers()
+        .into_iter()
+        .find(|solver| solver.day == day)
+        .unwrap()
+}
+
+#[test]
+fn answers_are_keyed_by_day_part_input_and_build() {
+    let directory = tempfile::tempdir().unwrap();
+    let cache = ResultCache::new(directory.path().to_path_buf());
+    let day6 = solver(6);
+
+    assert_eq!(cache.get(&day6, 1, "Time: 7\nDistance: 9\n"), None);
+    cache.put(&day6, 1, "Time: 7\nDistance: 9\n", "4").unwrap();
+
+    // the same input as the solver sees it
+    assert_eq!(
+        cache.get(&day6, 1, "Time: 7  \r\nDistance: 9\r\n"),
+        Some("4".to_string())
+    );
+    assert_eq!(cache.get(&day6, 2, "Time: 7\nDistance: 9\n"), None);
+    assert_eq!(cache.get(&day6, 1, "Time: 8\nDistance: 9\n"), None);
+
+    let rebuilt = Solver {
+        build_id: "rebuilt",
+        ..solver(6)
+    };
+    assert_eq!(cache.get(&rebuilt, 1, "Time: 7\nDistance: 9\n"), None);
+}
+
+#[test]
+fn rebuilding_a_day_only_drops_its_own_answers() {
+    let directory = tempfile::tempdir().unwrap();
+    let cache = ResultCache::new(directory.path().to_path_buf());
+    let (day1, day6) = (solver(1), solver(6));
+
+    cache.put(&day1, 1, "1abc2\n", "12").unwrap();
+    cache.put(&day6, 1, "Time: 7\nDistance: 9\n", "4").unwrap();
+
+    let rebuilt = Solver {
+        build_id: "rebuilt",
+        ..solver(6)
+    };
+    cache
+        .put(&rebuilt, 2, "Time: 7\nDistance: 9\n", "4")
+        .unwrap();
+
+    assert!(!cache.path(&day6, 1, "Time: 7\nDistance: 9\n").exists());
+    assert_eq!(cache.get(&day1, 1, "1abc2\n"), Some("12".to_string()));
+    assert_eq!(
+        cache.get(&rebuilt, 2, "Time: 7\nDistance: 9\n"),
+        Some("4".to_string())
+    );
+}
+
+#[test]
+fn build_ids_differ_between_days() {
+    let solvers = solvers();
+
+    for (i, solver) in solvers.iter().enumerate() {
+        assert!(!solver.build_id.is_empty());
+        assert!(solvers[i + 1..]
+            .iter()
+            .all(|other| other.build_id != solver.build_id));
+    }
+}
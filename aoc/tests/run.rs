@@ -0,0 +1,161 @@
+use aoc::day_directory;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+#[test]
+fn solves_stdin_and_several_files() {
+    let test = day_directory(6).join("test.txt");
+    let missing = day_directory(6).join("missing.txt");
+
+    let cache = tempfile::tempdir().unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env("AOC_CACHE_DIR", cache.path())
+        .args(["run", "6", "--part", "1", "--prefix", "--input", "-"])
+        .arg(&test)
+        .arg(&missing)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"Time:      7\nDistance:  9\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("-: 4\n{}: 288\n", test.display())
+    );
+
+    let errors = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        errors.starts_with(&format!("{}: ", missing.display())),
+        "{errors}"
+    );
+    assert!(errors.ends_with("1 of 3 inputs failed\n"), "{errors}");
+}
+
+fn run_json(cache: &Path, args: &[&str]) -> Vec<serde_json::Value> {
+    let test = day_directory(6).join("test.txt");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env("AOC_CACHE_DIR", cache)
+        .args(["run", "6", "--format", "json", "--input"])
+        .arg(&test)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn reports_json() {
+    let test = day_directory(6).join("test.txt");
+    let cache = tempfile::tempdir().unwrap();
+    let reports = run_json(cache.path(), &[]);
+
+    assert_eq!(reports.len(), 2);
+    for (report, (part, answer)) in reports.iter().zip([(1, "288"), (2, "71503")]) {
+        assert_eq!(report["day"], 6);
+        assert_eq!(report["part"], part);
+        assert_eq!(report["answer"], answer);
+        assert_eq!(report["input"], test.display().to_string());
+        assert_eq!(report["input_sha256"].as_str().unwrap().len(), 64);
+        assert_eq!(report["cached"], false);
+        assert!(report["parse_ns"].is_u64());
+        assert!(report["solve_ns"].is_u64());
+        assert_eq!(report["version"], "0.1.0");
+    }
+}
+
+#[test]
+fn answers_are_cached_unless_asked_not_to() {
+    let cache = tempfile::tempdir().unwrap();
+    run_json(cache.path(), &[]);
+
+    for report in run_json(cache.path(), &[]) {
+        assert_eq!(report["cached"], true);
+        assert!(report["solve_ns"].is_null());
+    }
+
+    for report in run_json(cache.path(), &["--no-cache"]) {
+        assert_eq!(report["cached"], false);
+        assert!(report["solve_ns"].is_u64());
+    }
+}
+
+#[test]
+fn hung_parts_time_out() {
+    let input = day_directory(5).join("input.txt");
+    let start = Instant::now();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run",
+            "5",
+            "--part",
+            "2",
+            "--no-cache",
+            "--timeout",
+            "0.2",
+            "--input",
+        ])
+        .arg(&input)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(start.elapsed() < Duration::from_secs(30));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Day 5 part 2: Timed out after 200ms\n"
+    );
+}
+
+#[test]
+fn explains_to_a_file_without_touching_stdout() {
+    let input = day_directory(8).join("test3.txt");
+    let directory = tempfile::tempdir().unwrap();
+    let trace = directory.path().join("trace.log");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run",
+            "8",
+            "--part",
+            "2",
+            "--no-cache",
+            "--explain",
+            "--input",
+        ])
+        .arg(&input)
+        .arg("--trace-file")
+        .arg(&trace)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "6\n");
+    assert!(output.stderr.is_empty());
+
+    let trace = std::fs::read_to_string(trace).unwrap();
+    assert!(
+        trace.contains("day{day=8}:part{part=2}: cycle start=\"11A\" lead_in=1 period=2 steps=[2]"),
+        "{trace}"
+    );
+}
@@ -0,0 +1,82 @@
+use aoc::solvers::solvers;
+use aoc::{scaffold, workspace_directory};
+use std::fs::{copy, create_dir_all, read_to_string};
+use std::path::Path;
+
+// the files a new day is registered in, copied so the real ones are left alone
+fn copy_workspace(to: &Path) {
+    for file in [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/solvers.rs",
+        "aoc/src/generate/mod.rs",
+        "fuzz/Cargo.toml",
+    ] {
+        let destination = to.join(file);
+        create_dir_all(destination.parent().unwrap()).unwrap();
+        copy(workspace_directory().join(file), destination).unwrap();
+    }
+}
+
+// the last line starting with prefix
+fn last_line<'a>(contents: &'a str, prefix: &str) -> &'a str {
+    contents
+        .lines()
+        .rfind(|line| line.starts_with(prefix))
+        .unwrap()
+}
+
+#[test]
+fn new_days_are_created_and_registered() {
+    let directory = tempfile::tempdir().unwrap();
+    let workspace = directory.path();
+    copy_workspace(workspace);
+
+    let read = |file: &str| read_to_string(workspace.join(file)).unwrap();
+
+    // the first day not registered yet, so this keeps working as days are added
+    let registered: Vec<u8> = solvers().iter().map(|solver| solver.day).collect();
+    let Some(day) = (1..=25).find(|day| !registered.contains(day)) else {
+        return;
+    };
+    let last_member = last_line(&read("Cargo.toml"), "    \"day").to_string();
+
+    let files = scaffold::new_day(workspace, day).unwrap();
+    assert_eq!(files.len(), 12);
+    assert!(files.iter().all(|file| file.exists()));
+
+    assert!(read("Cargo.toml").contains(&format!("{last_member}\n    \"day{day}\",\n]")));
+    assert!(read("aoc/Cargo.toml").contains(&format!("day{day} = {{ path = \"../day{day}\" }}\n")));
+    assert!(read("aoc/src/solvers.rs").contains(&format!(
+        "            build_id: build_ids::DAY{day},\n        }},\n    ]\n}}\n"
+    )));
+    // no parts are solved until they're written
+    assert!(read("aoc/src/solvers.rs").contains(&format!(
+        "            check: check::<day{day}::Day{day}>,\n            parts: &[],\n"
+    )));
+    assert!(read("fuzz/Cargo.toml").contains(&format!("path = \"fuzz_targets/day{day}.rs\"")));
+    assert!(read(&format!("fuzz/fuzz_targets/day{day}.rs"))
+        .contains(&format!("common::check::<day{day}::Day{day}>")));
+
+    let generators = read("aoc/src/generate/mod.rs");
+    assert!(generators.contains(&format!(
+        "            generate: day{day}::generate,\n        }},\n    ]\n}}\n"
+    )));
+    let modules: Vec<&str> = generators
+        .lines()
+        .filter(|line| line.starts_with("mod day"))
+        .collect();
+    assert!(modules.contains(&format!("mod day{day};").as_str()));
+    assert!(modules.is_sorted_by_key(|module| module.trim_end_matches(';')));
+    assert!(read(&format!("aoc/src/generate/day{day}.rs")).contains("pub fn generate("));
+
+    let lib = read(&format!("day{day}/src/lib.rs"));
+    assert!(lib.contains(&format!("impl Solution for Day{day} {{")));
+    assert!(lib.contains("fn test_part1()"));
+    assert!(!lib.contains("{{day}}"));
+    assert_eq!(read(&format!("day{day}/test.txt")), "");
+
+    let error = scaffold::new_day(workspace, day).unwrap_err();
+    assert_eq!(error, format!("day{day} already exists"));
+    assert!(scaffold::new_day(workspace, 26).is_err());
+}
@@ -0,0 +1,82 @@
+use aoc::serve::{self, Api};
+use aoc::solvers::solvers;
+use serde_json::Value;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+// a server on a free loopback port, and its base url
+fn start() -> String {
+    let server = Arc::new(serve::bind(0).unwrap());
+    let base_url = format!("http://{}", server.server_addr());
+    let api = Api {
+        solvers: solvers(),
+        cache: None,
+        limit: Some(Duration::from_secs(10)),
+    };
+
+    thread::spawn(move || serve::serve(&server, &api));
+
+    base_url
+}
+
+fn post(url: &str, body: &str) -> (u16, Value) {
+    let response = match ureq::post(url).send_string(body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => panic!("{e}"),
+    };
+
+    let status = response.status();
+    let body = response.into_string().unwrap();
+
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+#[test]
+fn solves_posted_inputs() {
+    let base_url = start();
+
+    let (status, report) = post(
+        &format!("{base_url}/solve/6/1"),
+        "Time:      7  15   30\nDistance:  9  40  200\n",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(report["day"], 6);
+    assert_eq!(report["part"], 1);
+    assert_eq!(report["answer"], "288");
+    assert!(report["solve_ns"].is_u64());
+
+    let (status, report) = post(&format!("{base_url}/solve/6/2"), "Time: x\n");
+    assert_eq!(status, 400);
+    assert!(
+        report["error"].as_str().unwrap().contains("line 1"),
+        "{report}"
+    );
+
+    let (status, _) = post(&format!("{base_url}/solve/99/1"), "");
+    assert_eq!(status, 404);
+    let (status, _) = post(&format!("{base_url}/solve/6/3"), "");
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn lists_days_and_their_parts() {
+    let base_url = start();
+
+    let body = ureq::get(&format!("{base_url}/days"))
+        .call()
+        .unwrap()
+        .into_string()
+        .unwrap();
+    let days: Value = serde_json::from_str(&body).unwrap();
+    let days = days.as_array().unwrap();
+
+    assert_eq!(days.len(), solvers().len());
+    assert_eq!(days[0]["day"], 1);
+    assert_eq!(days[0]["parts"], serde_json::json!([1, 2]));
+    assert_eq!(days[0]["version"], "0.1.0");
+
+    let error = ureq::post(&format!("{base_url}/days")).call().unwrap_err();
+    assert!(matches!(error, ureq::Error::Status(405, _)));
+}
@@ -0,0 +1,112 @@
+use aoc::submit::{self, History, HttpSubmitter, Outcome};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+// a stand-in for the puzzle site that knows day 1 part 1 is 142
+fn serve() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            let mut reader = BufReader::new(&stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+
+            let mut form = vec![0; content_length];
+            reader.read_exact(&mut form).unwrap();
+            let form = String::from_utf8(form).unwrap();
+
+            let body = match (request_line.split(' ').nth(1), form.as_str()) {
+                (Some("/2023/day/1/answer"), "level=1&answer=142") => "That's the right answer!",
+                (Some("/2023/day/1/answer"), "level=1&answer=200") => {
+                    "That's not the right answer; your answer is too high."
+                }
+                (Some("/2023/day/1/answer"), "level=1&answer=100") => {
+                    "That's not the right answer; your answer is too low."
+                }
+                (Some("/2023/day/1/answer"), _) => "That's not the right answer.",
+                _ => "You don't seem to be solving the right level. Did you already complete it?",
+            };
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+#[test]
+fn submissions_are_recorded_and_used_to_refuse_answers() {
+    let (base_url, requests) = serve();
+    let submitter = HttpSubmitter::new(&base_url, "secret");
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("submissions.toml");
+    let mut history = History::load(&path).unwrap();
+
+    let mut submit = |answer| submit::submit(&mut history, &submitter, 2023, 1, 1, answer);
+    assert_eq!(submit("200"), Ok(Outcome::TooHigh));
+    assert_eq!(submit("100"), Ok(Outcome::TooLow));
+    assert_eq!(submit("141"), Ok(Outcome::Wrong));
+    assert!(submit("141").is_err());
+    assert!(submit("250").is_err());
+    assert!(submit("99").is_err());
+    assert_eq!(submit("142"), Ok(Outcome::Correct));
+    assert!(submit("143").is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+    history.save(&path).unwrap();
+    let history = History::load(&path).unwrap();
+    let outcomes: Vec<_> = history
+        .submissions
+        .iter()
+        .map(|submission| (submission.answer.as_str(), submission.outcome))
+        .collect();
+    assert_eq!(
+        outcomes,
+        [
+            ("200", Outcome::TooHigh),
+            ("100", Outcome::TooLow),
+            ("141", Outcome::Wrong),
+            ("142", Outcome::Correct)
+        ]
+    );
+}
+
+#[test]
+fn unrecognised_responses_are_not_recorded() {
+    let (base_url, _) = serve();
+    let submitter = HttpSubmitter::new(&base_url, "secret");
+    let mut history = History::default();
+
+    let error = submit::submit(&mut history, &submitter, 2023, 2, 1, "8").unwrap_err();
+    assert!(error.contains("already complete"), "{error}");
+    assert!(history.submissions.is_empty());
+}
@@ -0,0 +1,114 @@
+use aoc::inputs::{CheckedInFetcher, InputCache};
+use aoc::solvers::{solvers, Solver};
+use aoc::summary::{self, Status};
+use common::{cancel, Error, Solved};
+use std::time::Duration;
+
+fn solver(day: u8) -> Solver {
+    solvers()
+        .into_iter()
+        .find(|solver| solver.day == day)
+        .unwrap()
+}
+
+#[test]
+fn every_part_gets_a_status() {
+    let directory = tempfile::tempdir().unwrap();
+    let inputs = InputCache::new(directory.path().to_path_buf(), Box::new(CheckedInFetcher));
+
+    let wrong = Solver {
+        solve: |_, _| {
+            Ok(Solved {
+                answer: "1".to_string(),
+                parse_time: Duration::ZERO,
+                solve_time: Duration::ZERO,
+            })
+        },
+        ..solver(1)
+    };
+    let hung = Solver {
+        solve: |_, _| loop {
+            cancel::checkpoint()?;
+        },
+        ..solver(2)
+    };
+    let broken = Solver {
+        solve: |_, _| Err(Error::parse("Bad input")),
+        ..solver(3)
+    };
+
+    let rows = summary::run_all(
+        &[wrong, hung, broken, solver(6)],
+        &[1, 2],
+        &inputs,
+        None,
+        Some(Duration::from_millis(100)),
+    )
+    .unwrap();
+
+    let statuses: Vec<_> = rows
+        .iter()
+        .map(|row| (row.day, row.part, &row.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            (
+                1,
+                1,
+                &Status::Mismatch {
+                    expected: "55816".to_string()
+                }
+            ),
+            (
+                1,
+                2,
+                &Status::Mismatch {
+                    expected: "54980".to_string()
+                }
+            ),
+            (2, 1, &Status::Timeout),
+            (2, 2, &Status::Timeout),
+            (
+                3,
+                1,
+                &Status::Error("Failed to parse input: Bad input".to_string())
+            ),
+            (
+                3,
+                2,
+                &Status::Error("Failed to parse input: Bad input".to_string())
+            ),
+            (6, 1, &Status::Ok),
+            (6, 2, &Status::Ok),
+        ]
+    );
+
+    let table = summary::table(&rows);
+    assert!(table.starts_with("day part  answer  "), "{table}");
+    assert!(table.contains("\n  6    2  38017587  "), "{table}");
+}
+
+#[test]
+fn checks_every_recorded_answer() {
+    let rows = summary::check(&solver(6), &[1, 2], None).unwrap();
+
+    assert_eq!(
+        summary::diff(&rows),
+        "pass  input.txt  part 1  4403592\n\
+         pass  input.txt  part 2  38017587\n\
+         pass  test.txt   part 1  288\n\
+         pass  test.txt   part 2  71503"
+    );
+
+    let wrong = Solver {
+        solve: |_, _| Err(Error::NoSolution),
+        ..solver(6)
+    };
+    let rows = summary::check(&wrong, &[2], None).unwrap();
+    assert_eq!(
+        summary::diff(&rows),
+        "FAIL  input.txt  part 2  -  error: No solution found\n\
+         FAIL  test.txt   part 2  -  error: No solution found"
+    );
+}
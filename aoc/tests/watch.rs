@@ -0,0 +1,49 @@
+use aoc::watch::Watcher;
+use std::fs::{create_dir, write};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn reports_changed_sources_and_inputs_only() {
+    let directory = tempfile::tempdir().unwrap();
+    let src = directory.path().join("src");
+    create_dir(&src).unwrap();
+
+    let mut watcher = Watcher::new(&[directory.path().to_path_buf()]).unwrap();
+
+    let root = directory.path().to_path_buf();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        write(root.join(".lib.rs.swp"), "").unwrap();
+        write(root.join("src").join("lib.rs"), "// edited").unwrap();
+        write(root.join("test.txt"), "1\n").unwrap();
+    });
+
+    let changed = watcher.wait().unwrap();
+    writer.join().unwrap();
+
+    assert_eq!(
+        changed,
+        [src.join("lib.rs"), directory.path().join("test.txt")]
+    );
+}
+
+#[test]
+fn watches_directories_made_after_starting() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut watcher = Watcher::new(&[directory.path().to_path_buf()]).unwrap();
+
+    let tests = directory.path().join("tests");
+    let created = tests.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        create_dir(&created).unwrap();
+        write(created.join("sample.rs"), "").unwrap();
+    });
+
+    assert_eq!(watcher.wait().unwrap(), [tests.join("sample.rs")]);
+    writer.join().unwrap();
+
+    write(tests.join("sample.rs"), "// edited").unwrap();
+    assert_eq!(watcher.wait().unwrap(), [tests.join("sample.rs")]);
+}
@@ -0,0 +1,141 @@
+use crate::{Error, STACK_SIZE};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+// how long a cancelled solver gets to reach a checkpoint before it's abandoned
+const GRACE: Duration = Duration::from_secs(1);
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+// shared between whoever runs a solver and the solver's checkpoints
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn checkpoint(&self) -> Result<(), Error> {
+        match self.is_cancelled() {
+            true => Err(Error::Cancelled),
+            false => Ok(()),
+        }
+    }
+
+    // runs f with this as the thread's token, so checkpoints inside it can see cancellation
+    pub fn install<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let result = f();
+        CURRENT.with(|current| current.replace(previous));
+
+        result
+    }
+}
+
+// the thread's token, for handing to work done on other threads
+pub fn current() -> CancelToken {
+    CURRENT.with(|current| current.borrow().clone().unwrap_or_default())
+}
+
+// for long loops: fails with Error::Cancelled once whoever is running the solver gives up on it
+pub fn checkpoint() -> Result<(), Error> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) => token.checkpoint(),
+        None => Ok(()),
+    })
+}
+
+// runs f on a thread with a STACK_SIZE stack, as the deepest solvers need more than the main
+// thread has. f sees the caller's token, so cancelling the caller cancels f too.
+pub fn on_solver_thread<T: Send>(f: impl FnOnce() -> T + Send) -> Result<T, Error> {
+    let token = current();
+
+    thread::scope(|scope| {
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || token.install(f))?
+            .join()
+            .map_err(|_| Error::invalid_state("The solver panicked"))
+    })
+}
+
+// runs f on its own thread, cancelling it if it takes longer than limit. A solver that never
+// reaches a checkpoint is left running in the background rather than waited for.
+pub fn with_timeout<T: Send + 'static>(
+    limit: Duration,
+    f: impl FnOnce() -> Result<T, Error> + Send + 'static,
+) -> Result<T, Error> {
+    let token = CancelToken::new();
+    let (sender, receiver) = mpsc::channel();
+
+    let worker_token = token.clone();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(worker_token.install(f));
+        })?;
+
+    match receiver.recv_timeout(limit) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            let _ = receiver.recv_timeout(GRACE);
+            Err(Error::TimedOut(limit))
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err(Error::invalid_state("The solver panicked"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checkpoints_stop_runaway_loops() {
+        let result: Result<(), Error> = with_timeout(Duration::from_millis(50), || loop {
+            checkpoint()?;
+        });
+        assert!(matches!(result, Err(Error::TimedOut(_))));
+
+        assert_eq!(with_timeout(Duration::from_secs(10), || Ok(5)).unwrap(), 5);
+        assert!(checkpoint().is_ok());
+    }
+
+    #[test]
+    fn test_solver_thread_has_a_deep_stack() {
+        fn depth(n: u64) -> u64 {
+            let padding = std::hint::black_box([0u8; 1024]);
+            match n {
+                0 => padding[0] as u64,
+                _ => 1 + depth(n - 1),
+            }
+        }
+
+        // deeper than the 8MiB main thread allows
+        assert_eq!(on_solver_thread(|| depth(20_000)).unwrap(), 20_000);
+
+        let token = CancelToken::new();
+        token.cancel();
+        let result = token.install(|| on_solver_thread(checkpoint));
+        assert!(matches!(result, Ok(Err(Error::Cancelled))));
+
+        let result = on_solver_thread(|| panic!("boom"));
+        assert!(matches!(result, Err(Error::InvalidState(_))));
+    }
+}
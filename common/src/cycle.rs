@@ -0,0 +1,186 @@
+use crate::{cancel, Error};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// where a sequence of states starting from some state starts repeating
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    // steps taken before the first state that's part of the cycle
+    pub lead_in: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // the earliest step that reaches the same state as step n
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.lead_in {
+            n
+        } else {
+            self.lead_in + (n - self.lead_in) % self.period
+        }
+    }
+}
+
+fn advance<S>(mut state: S, step: &mut impl FnMut(&S) -> S, steps: usize) -> Result<S, Error> {
+    for _ in 0..steps {
+        cancel::checkpoint()?;
+        state = step(&state);
+    }
+
+    Ok(state)
+}
+
+// Brent's algorithm: keeps only two states, and steps fewer times than Floyd's. Only returns
+// once a state repeats, so step must eventually lead back to an earlier state.
+pub fn brent<S: Clone + PartialEq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> S,
+) -> Result<Cycle, Error> {
+    // the hare searches ever longer stretches for the tortoise, which waits at each power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+
+    while tortoise != hare {
+        cancel::checkpoint()?;
+
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // a period apart, they first meet where the cycle starts
+    let mut tortoise = start.clone();
+    let mut hare = advance(start.clone(), &mut step, period)?;
+    let mut lead_in = 0;
+
+    while tortoise != hare {
+        cancel::checkpoint()?;
+
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        lead_in += 1;
+    }
+
+    Ok(Cycle { lead_in, period })
+}
+
+// Floyd's algorithm: keeps only two states, with the hare going twice as fast as the tortoise
+pub fn floyd<S: Clone + PartialEq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> S,
+) -> Result<Cycle, Error> {
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        cancel::checkpoint()?;
+
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+
+    // the meeting point is as far from the cycle's start as the start state is
+    let mut tortoise = start.clone();
+    let mut lead_in = 0;
+
+    while tortoise != hare {
+        cancel::checkpoint()?;
+
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        lead_in += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+
+    while tortoise != hare {
+        cancel::checkpoint()?;
+
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Ok(Cycle { lead_in, period })
+}
+
+// remembers every state, so each is only stepped from once: best when stepping is expensive and
+// states are small. The states are returned too, the one after i steps at index i, so the state
+// at any step can be looked up with reduce rather than stepped to again.
+pub fn hashed<S: Clone + Eq + Hash>(
+    start: &S,
+    mut step: impl FnMut(&S) -> S,
+) -> Result<(Cycle, Vec<S>), Error> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = start.clone();
+
+    for i in 0.. {
+        cancel::checkpoint()?;
+
+        if let Some(&first) = seen.get(&state) {
+            let mut states = vec![None; i];
+            for (state, steps) in seen {
+                states[steps] = Some(state);
+            }
+
+            let cycle = Cycle {
+                lead_in: first,
+                period: i - first,
+            };
+            return Ok((cycle, states.into_iter().flatten().collect()));
+        }
+
+        let next = step(&state);
+        seen.insert(state, i);
+        state = next;
+    }
+
+    unreachable!("a state repeats before the steps run out")
+}
+
+// the state after n steps, taking only as many as the cycle needs
+pub fn state_at<S>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    cycle: &Cycle,
+    n: usize,
+) -> Result<S, Error> {
+    advance(start, &mut step, cycle.reduce(n))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 3, 10, then 101, 2, 5, 26, 167, 95 over and over
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_detectors_agree_and_fast_forward() {
+        let expected = Cycle {
+            lead_in: 2,
+            period: 6,
+        };
+
+        assert_eq!(brent(&3, step).unwrap(), expected);
+        assert_eq!(floyd(&3, step).unwrap(), expected);
+        let (cycle, states) = hashed(&3, step).unwrap();
+        assert_eq!(cycle, expected);
+        assert_eq!(states, [3, 10, 101, 2, 5, 26, 167, 95]);
+        assert_eq!(floyd(&0, |_| 0).unwrap().period, 1);
+
+        let slow = (0..1000).fold(3, |x, _| step(&x));
+        assert_eq!(state_at(3, step, &expected, 1000).unwrap(), slow);
+        assert_eq!(state_at(3, step, &expected, 1).unwrap(), 10);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    // line and column are 1-based, and filled in by whichever caller knows them
+    Parse {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    InvalidState(String),
+    NoSolution,
+    Unimplemented,
+    // a checkpoint found the solver's run had been given up on
+    Cancelled,
+    TimedOut(Duration),
+}
+
+impl Error {
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::Parse {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        Self::InvalidState(message.into())
+    }
+
+    pub fn at_line(mut self, line_number: usize) -> Self {
+        if let Self::Parse { line, .. } = &mut self {
+            line.get_or_insert(line_number);
+        }
+        self
+    }
+
+    pub fn at_column(mut self, column_number: usize) -> Self {
+        if let Self::Parse { column, .. } = &mut self {
+            column.get_or_insert(column_number);
+        }
+        self
+    }
+
+    pub fn at(self, line: usize, column: usize) -> Self {
+        self.at_line(line).at_column(column)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to read input: {e}"),
+            Self::Parse {
+                message,
+                line: Some(line),
+                column: Some(column),
+            } => write!(
+                f,
+                "Failed to parse input at line {line}, column {column}: {message}"
+            ),
+            Self::Parse {
+                message,
+                line: Some(line),
+                column: None,
+            } => write!(f, "Failed to parse input at line {line}: {message}"),
+            Self::Parse { message, .. } => write!(f, "Failed to parse input: {message}"),
+            Self::InvalidState(message) => write!(f, "Invalid puzzle state: {message}"),
+            Self::NoSolution => write!(f, "No solution found"),
+            Self::Unimplemented => write!(f, "Not implemented"),
+            Self::Cancelled => write!(f, "Cancelled"),
+            Self::TimedOut(limit) => write!(f, "Timed out after {limit:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_location_is_only_set_once() {
+        let error = Error::parse("Unexpected tile 'x'").at_column(4).at(2, 1);
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input at line 2, column 4: Unexpected tile 'x'"
+        );
+    }
+}
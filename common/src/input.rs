@@ -0,0 +1,69 @@
+use crate::Error;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::Path;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+// puts an input however it was saved into the shape the parsers expect: unix line endings, no
+// byte order mark, no trailing whitespace or blank lines, and one newline at the end. Puzzle
+// inputs are plain ASCII, so anything else left over is reported where it was found.
+pub fn normalize(raw: &str) -> Result<String, Error> {
+    let raw = raw.strip_prefix(BYTE_ORDER_MARK).unwrap_or(raw);
+    let unix = raw.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut lines: Vec<&str> = unix.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    for (row, line) in lines.iter().enumerate() {
+        if let Some((column, character)) = line.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+            return Err(
+                Error::parse(format!("Unexpected non-ASCII character '{character}'"))
+                    .at(row + 1, column + 1),
+            );
+        }
+    }
+
+    let mut normalized = lines.join("\n");
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    Ok(normalized)
+}
+
+// reads a file, or standard input when the path is "-"
+pub fn read(path: &Path) -> Result<String, Error> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        Ok(read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let raw = "\u{feff}Time:   7  15\r\nDistance:  9  40  \r\n\r\n\n";
+
+        assert_eq!(normalize(raw).unwrap(), "Time:   7  15\nDistance:  9  40\n");
+        assert_eq!(normalize("\n \n").unwrap(), "");
+    }
+
+    #[test]
+    fn test_non_ascii_is_located() {
+        let error = normalize("..#\n.\u{2026}.\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input at line 2, column 2: Unexpected non-ASCII character '\u{2026}'"
+        );
+    }
+}
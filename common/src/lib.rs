@@ -0,0 +1,128 @@
+pub mod cancel;
+pub mod cycle;
+mod error;
+pub mod input;
+pub mod parse;
+pub mod repl;
+pub mod search;
+pub mod trace;
+
+pub use crate::error::Error;
+use std::env;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use tracing::Level;
+
+// some solvers recurse deeply on the real inputs, beyond the default stack of spawned threads
+pub const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+pub trait Solution {
+    type Parsed;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer, Error>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer, Error>;
+}
+
+pub struct Solved {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<Solved, Error> {
+    let start = Instant::now();
+    let parsed = tracing::debug_span!("parse").in_scope(|| S::parse(&input::normalize(input)?))?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = tracing::debug_span!("part", part).in_scope(|| match part {
+        1 => S::part1(&parsed),
+        2 => S::part2(&parsed),
+        _ => Err(Error::Unimplemented),
+    })?;
+    let solve_time = start.elapsed();
+
+    Ok(Solved {
+        answer: answer.to_string(),
+        parse_time,
+        solve_time,
+    })
+}
+
+// parses without solving, for checking inputs the solvers might take forever over
+pub fn check<S: Solution>(input: &str) -> Result<(), Error> {
+    S::parse(&input::normalize(input)?).map(|_| ())
+}
+
+// entry point for the per-day binaries: solves each input named on the command line ("-" for
+// standard input), or input.txt in the current directory. --prefix labels each answer with
+// the input it came from, and --explain or --trace=LEVEL shows the solver's workings on stderr.
+pub fn run<S: Solution>() -> ExitCode {
+    let mut prefix = false;
+    let mut trace = None;
+    let mut paths = Vec::new();
+
+    for argument in env::args().skip(1) {
+        match argument.as_str() {
+            "--prefix" => prefix = true,
+            "--explain" => trace = Some(Level::DEBUG),
+            _ => match argument.strip_prefix("--trace=") {
+                Some(level) => match level.parse() {
+                    Ok(level) => trace = Some(level),
+                    Err(_) => {
+                        eprintln!("Unknown trace level '{level}'");
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => paths.push(PathBuf::from(argument)),
+            },
+        }
+    }
+
+    if let Some(level) = trace {
+        // stderr can't fail to open
+        let _ = trace::init(level, None);
+    }
+
+    if paths.is_empty() {
+        paths.push(PathBuf::from("input.txt"));
+    }
+
+    let mut status = ExitCode::SUCCESS;
+
+    // one bad input shouldn't stop the rest being solved
+    for path in &paths {
+        let label = if prefix {
+            format!("{}: ", path.display())
+        } else {
+            String::new()
+        };
+
+        if let Err(e) = run_input::<S>(path, &label) {
+            eprintln!("{}: {e}", path.display());
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    status
+}
+
+fn run_input<S: Solution>(path: &Path, label: &str) -> Result<(), Error> {
+    let input = input::read(path)?;
+
+    for part in [1, 2] {
+        match cancel::on_solver_thread(|| solve::<S>(&input, part))? {
+            Ok(Solved { answer, .. }) => println!("{label}{answer}"),
+            Err(Error::Unimplemented) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
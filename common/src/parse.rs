@@ -0,0 +1,98 @@
+use crate::Error;
+
+// parses each line on its own, tagging any failure with its line number
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    split_lines(input)
+        .map(|(line_number, line)| parse(line).map_err(|e| e.at_line(line_number)))
+        .collect()
+}
+
+// as lines, for when the caller has already skipped a header of first_line - 1 lines
+pub fn numbered_lines<'a, T>(
+    lines: impl Iterator<Item = &'a str>,
+    first_line: usize,
+    mut parse: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    lines
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.at_line(first_line + i)))
+        .collect()
+}
+
+pub fn number<T: std::str::FromStr>(token: &str) -> Result<T, Error> {
+    token
+        .trim()
+        .parse()
+        .map_err(|_| Error::parse(format!("Expected a number, found '{token}'")))
+}
+
+// every line with its line number, counting from 1
+pub fn split_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+// every paragraph with the line number it starts on. Any number of blank lines separates
+// paragraphs, and none are empty.
+pub fn split_paragraphs(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut lines = input.split_inclusive('\n').enumerate().peekable();
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        while let Some((_, line)) = lines.next_if(|(_, line)| line.trim().is_empty()) {
+            offset += line.len();
+        }
+
+        let (first, _) = *lines.peek()?;
+        let start = offset;
+
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            offset += line.len();
+        }
+
+        Some((first + 1, input[start..offset].trim_end_matches('\n')))
+    })
+}
+
+// parses each paragraph on its own, keeping line numbers relative to the input
+pub fn paragraphs<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    split_paragraphs(input)
+        .map(|(first_line, paragraph)| {
+            parse(paragraph).map_err(|e| match e {
+                Error::Parse {
+                    message,
+                    line,
+                    column,
+                } => Error::Parse {
+                    message,
+                    line: Some(first_line + line.map_or(0, |line| line - 1)),
+                    column,
+                },
+                e => e,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_paragraphs() {
+        let input = "\nseeds: 79 14\n\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+
+        assert_eq!(
+            split_paragraphs(input).collect::<Vec<_>>(),
+            [
+                (2, "seeds: 79 14"),
+                (5, "seed-to-soil map:\n50 98 2\n52 50 48")
+            ]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{input, Error, Solution};
+use std::fmt::Write as _;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+// a command a day offers for poking at its puzzle state, given the words after its name
+pub struct Command<S> {
+    pub name: &'static str,
+    // the arguments, for help, e.g. "SEED"
+    pub arguments: &'static str,
+    pub help: &'static str,
+    pub run: fn(&mut S, &[&str]) -> Result<String, String>,
+}
+
+// a day whose parsed input can be explored interactively
+pub trait Repl: Solution {
+    // whatever the commands share, which some of them change as they go
+    type State;
+
+    fn start(parsed: Self::Parsed) -> Result<Self::State, Error>;
+
+    fn commands() -> Vec<Command<Self::State>>;
+}
+
+// the argument at index, parsed, or a message saying what was expected
+pub fn argument<T: FromStr>(arguments: &[&str], index: usize, name: &str) -> Result<T, String> {
+    let argument = arguments
+        .get(index)
+        .ok_or_else(|| format!("Expected {name}"))?;
+
+    argument
+        .parse()
+        .map_err(|_| format!("Expected {name}, found '{argument}'"))
+}
+
+fn help<S>(commands: &[Command<S>]) -> String {
+    let usages: Vec<String> = commands
+        .iter()
+        .map(|command| format!("{} {}", command.name, command.arguments))
+        .chain(["help".to_string(), "quit".to_string()])
+        .collect();
+    let width = usages
+        .iter()
+        .map(|usage| usage.trim_end().len())
+        .max()
+        .unwrap_or_default();
+
+    let helps = commands
+        .iter()
+        .map(|command| command.help)
+        .chain(["List the commands", "Stop exploring"]);
+
+    let mut help = String::new();
+    for (usage, text) in usages.iter().zip(helps) {
+        let _ = writeln!(help, "{:<width$}  {text}", usage.trim_end());
+    }
+
+    help
+}
+
+// run for a particular day, so days can be told apart at runtime
+pub type Runner = fn(&str, &mut dyn BufRead, &mut dyn Write) -> Result<(), Error>;
+
+// parses the input once, then runs a command per line read until quit or the end of the input.
+// A command that fails says why and leaves the state as it was for the next.
+pub fn run<R: Repl>(
+    input: &str,
+    reader: &mut dyn BufRead,
+    writer: &mut dyn Write,
+) -> Result<(), Error> {
+    let mut state = R::start(R::parse(&input::normalize(input)?)?)?;
+    let commands = R::commands();
+
+    let mut line = String::new();
+
+    loop {
+        write!(writer, "> ")?;
+        writer.flush()?;
+
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            writeln!(writer)?;
+            return Ok(());
+        }
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, arguments)) = words.split_first() else {
+            continue;
+        };
+
+        let output = match name {
+            "help" => Ok(help(&commands)),
+            "quit" | "exit" => return Ok(()),
+            _ => match commands.iter().find(|command| command.name == name) {
+                Some(command) => (command.run)(&mut state, arguments),
+                None => Err(format!("Unknown command '{name}', try help")),
+            },
+        };
+
+        match output {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => writeln!(writer, "{}", output.trim_end())?,
+            Err(message) => writeln!(writer, "error: {message}")?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Counter;
+
+    impl Solution for Counter {
+        type Parsed = u64;
+        type Answer = u64;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Error> {
+            crate::parse::number(input)
+        }
+
+        fn part1(count: &Self::Parsed) -> Result<Self::Answer, Error> {
+            Ok(*count)
+        }
+
+        fn part2(_: &Self::Parsed) -> Result<Self::Answer, Error> {
+            Err(Error::Unimplemented)
+        }
+    }
+
+    impl Repl for Counter {
+        type State = u64;
+
+        fn start(count: Self::Parsed) -> Result<Self::State, Error> {
+            Ok(count)
+        }
+
+        fn commands() -> Vec<Command<Self::State>> {
+            vec![Command {
+                name: "add",
+                arguments: "N",
+                help: "Add N to the count",
+                run: |count, arguments| {
+                    *count += argument::<u64>(arguments, 0, "a number")?;
+                    Ok(count.to_string())
+                },
+            }]
+        }
+    }
+
+    fn session(input: &str, commands: &str) -> String {
+        let mut output = Vec::new();
+        run::<Counter>(input, &mut commands.as_bytes(), &mut output).unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_commands_share_state_and_survive_errors() {
+        assert_eq!(
+            session("3\n", "add 2\n\nadd x\nsubtract 1\nadd 5\nquit\nadd 1\n"),
+            "> 5\n> > error: Expected a number, found 'x'\n\
+             > error: Unknown command 'subtract', try help\n> 10\n> "
+        );
+        assert_eq!(session("3\n", ""), "> \n");
+        assert!(session("3\n", "help\n").contains("add N  Add N to the count\n"));
+    }
+}
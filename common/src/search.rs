@@ -0,0 +1,316 @@
+use crate::{cancel, Error};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// the states a search has expanded, so none is expanded twice
+pub trait Visited<S> {
+    // false if the state had already been visited. parent is the state it was reached from,
+    // None for where the search started
+    fn insert(&mut self, state: &S, parent: Option<&S>) -> bool;
+
+    fn contains(&self, state: &S) -> bool;
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> for HashSet<S> {
+    fn insert(&mut self, state: &S, _: Option<&S>) -> bool {
+        HashSet::insert(self, state.clone())
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        HashSet::contains(self, state)
+    }
+}
+
+// for states that number densely from zero, such as a grid's cells: much quicker than hashing
+pub struct DenseVisited<F> {
+    seen: Vec<bool>,
+    index: F,
+}
+
+impl<F> DenseVisited<F> {
+    // index must give every state a different number below size
+    pub fn new(size: usize, index: F) -> Self {
+        Self {
+            seen: vec![false; size],
+            index,
+        }
+    }
+}
+
+impl<S, F: Fn(&S) -> usize> Visited<S> for DenseVisited<F> {
+    fn insert(&mut self, state: &S, _: Option<&S>) -> bool {
+        !std::mem::replace(&mut self.seen[(self.index)(state)], true)
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        self.seen[(self.index)(state)]
+    }
+}
+
+// remembers how each state was first reached, to reconstruct the path to it
+pub struct Parents<S> {
+    parents: HashMap<S, Option<S>>,
+}
+
+impl<S> Default for Parents<S> {
+    fn default() -> Self {
+        Self {
+            parents: HashMap::new(),
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash> Parents<S> {
+    // from where the search started to the state, or None if it wasn't reached
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+
+        while let Some(parent) = self.parents.get(path.last()?)?.as_ref() {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> for Parents<S> {
+    fn insert(&mut self, state: &S, parent: Option<&S>) -> bool {
+        if self.parents.contains_key(state) {
+            return false;
+        }
+
+        self.parents.insert(state.clone(), parent.cloned());
+        true
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        self.parents.contains_key(state)
+    }
+}
+
+// expands states in the order the frontier gives them up, stopping at the first goal
+fn traverse<S: Clone, I: IntoIterator<Item = S>>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    visited: &mut impl Visited<S>,
+    depth_first: bool,
+) -> Result<Option<(S, usize)>, Error> {
+    let mut frontier: VecDeque<(S, Option<S>, usize)> =
+        starts.into_iter().map(|start| (start, None, 0)).collect();
+
+    // a stack for depth first, a queue for breadth first
+    let pop = if depth_first {
+        VecDeque::pop_back
+    } else {
+        VecDeque::pop_front
+    };
+
+    while let Some((state, parent, steps)) = pop(&mut frontier) {
+        cancel::checkpoint()?;
+
+        if !visited.insert(&state, parent.as_ref()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Ok(Some((state, steps)));
+        }
+
+        for next in neighbours(&state) {
+            if !visited.contains(&next) {
+                frontier.push_back((next, Some(state.clone()), steps + 1));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+// breadth first: the goal found is one fewest steps from a start, returned with the steps. With
+// a goal that's never met, it visits everything reachable.
+pub fn bfs<S: Clone, I: IntoIterator<Item = S>>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    visited: &mut impl Visited<S>,
+) -> Result<Option<(S, usize)>, Error> {
+    traverse(starts, neighbours, is_goal, visited, false)
+}
+
+// depth first: the goal found is any reachable one, returned with the steps taken to it
+pub fn dfs<S: Clone, I: IntoIterator<Item = S>>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    visited: &mut impl Visited<S>,
+) -> Result<Option<(S, usize)>, Error> {
+    traverse(starts, neighbours, is_goal, visited, true)
+}
+
+// a state waiting in the queue, ordered by its cost plus the estimate of what's left
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+    parent: Option<S>,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.estimate.cmp(&other.estimate)
+    }
+}
+
+// A*: the cheapest goal, and its cost, given neighbours with the cost of moving to each. States
+// aren't reopened once visited, so the heuristic must be consistent as well as never
+// overestimating: 0 at goals, and never falling by more than the cost of the move between
+// neighbours. Otherwise a state might first be reached the dear way, and a dearer goal found.
+pub fn astar<S: Clone, C, I: IntoIterator<Item = (S, C)>>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    visited: &mut impl Visited<S>,
+) -> Result<Option<(S, C)>, Error>
+where
+    C: Copy + Ord + Add<Output = C> + Default,
+{
+    let mut queue: BinaryHeap<Reverse<Queued<S, C>>> = starts
+        .into_iter()
+        .map(|state| {
+            Reverse(Queued {
+                estimate: heuristic(&state),
+                cost: C::default(),
+                state,
+                parent: None,
+            })
+        })
+        .collect();
+
+    while let Some(Reverse(Queued {
+        cost,
+        state,
+        parent,
+        ..
+    })) = queue.pop()
+    {
+        cancel::checkpoint()?;
+
+        if !visited.insert(&state, parent.as_ref()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Ok(Some((state, cost)));
+        }
+
+        for (next, step_cost) in neighbours(&state) {
+            if visited.contains(&next) {
+                continue;
+            }
+
+            let cost = cost + step_cost;
+            queue.push(Reverse(Queued {
+                estimate: cost + heuristic(&next),
+                cost,
+                state: next,
+                parent: Some(state.clone()),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+// Dijkstra's algorithm: A* with no idea how far the goal is
+pub fn dijkstra<S: Clone, C, I: IntoIterator<Item = (S, C)>>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    visited: &mut impl Visited<S>,
+) -> Result<Option<(S, C)>, Error>
+where
+    C: Copy + Ord + Add<Output = C> + Default,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal, visited)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 to 9 in a line, stepping by 1 for a cost of 3 or by 2 for a cost of 4
+    fn steps(&n: &u32) -> Vec<(u32, u32)> {
+        [(n + 1, 3), (n + 2, 4)]
+            .into_iter()
+            .filter(|&(next, _)| next < 10)
+            .collect()
+    }
+
+    fn unweighted(n: &u32) -> Vec<u32> {
+        steps(n).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_searches_find_goals_and_paths() {
+        let mut parents = Parents::default();
+        assert_eq!(
+            bfs([0], unweighted, |&n| n == 8, &mut parents).unwrap(),
+            Some((8, 4))
+        );
+        assert_eq!(parents.path(&8), Some(vec![0, 2, 4, 6, 8]));
+        assert_eq!(parents.path(&9), None);
+        assert_eq!(parents.path(&0), Some(vec![0]));
+
+        let mut visited = DenseVisited::new(10, |&n: &u32| n as usize);
+        assert_eq!(dfs([0], unweighted, |_| false, &mut visited).unwrap(), None);
+        assert!((0..10).all(|n| visited.contains(&n)));
+
+        // steps of 2 for 4 beat pairs of steps of 1 for 6
+        let mut parents = Parents::default();
+        assert_eq!(
+            dijkstra([0], steps, |&n| n == 8, &mut parents).unwrap(),
+            Some((8, 16))
+        );
+        assert_eq!(parents.path(&8), Some(vec![0, 2, 4, 6, 8]));
+
+        // every step forwards costs at least 2
+        let mut visited = HashSet::new();
+        assert_eq!(
+            astar(
+                [0],
+                steps,
+                |&n| (8 - n.min(8)) * 2,
+                |&n| n == 8,
+                &mut visited
+            )
+            .unwrap(),
+            Some((8, 16))
+        );
+
+        let mut visited = HashSet::new();
+        assert_eq!(
+            dijkstra([0], steps, |&n| n == 10, &mut visited).unwrap(),
+            None
+        );
+        assert_eq!(visited.len(), 10);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Error;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::Level;
+
+// shows solvers' tracing events up to level, on stderr unless a file is given, so stdout is
+// left to the answers
+pub fn init(level: Level, file: Option<&Path>) -> Result<(), Error> {
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_target(false)
+        .without_time();
+
+    // a subscriber that's already installed keeps the events
+    let _ = match file {
+        Some(path) => subscriber
+            .with_ansi(false)
+            .with_writer(Mutex::new(File::create(path)?))
+            .try_init(),
+        None => subscriber.with_writer(std::io::stderr).try_init(),
+    };
+
+    Ok(())
+}
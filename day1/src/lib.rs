@@ -0,0 +1,102 @@
+use common::{Error, Solution};
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<String>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<Self::Answer, Error> {
+        Ok(lines.iter().filter_map(|line| get_plain_number(line)).sum())
+    }
+
+    fn part2(lines: &Self::Parsed) -> Result<Self::Answer, Error> {
+        Ok(lines.iter().filter_map(|line| get_number(line)).sum())
+    }
+}
+
+// only numeric digits count, spelled out ones are ignored
+pub fn get_plain_number(input: &str) -> Option<u64> {
+    let first = input.chars().find(|c| c.is_ascii_digit())?;
+    let last = input.chars().rev().find(|c| c.is_ascii_digit())?;
+
+    format!("{first}{last}").parse().ok()
+}
+
+pub fn get_number(input: &str) -> Option<u64> {
+    let digits: Vec<char> = get_digits(input);
+
+    let number_string: String = digits.into_iter().collect();
+
+    number_string.parse().ok()
+}
+
+fn get_digits(input: &str) -> Vec<char> {
+    vec![get_first_digit(input), get_last_digit(input)]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn get_first_digit(input: &str) -> Option<char> {
+    for i in 0..input.len() {
+        for j in i..input.len() {
+            if let Some(sub_str) = input.get(i..j + 1) {
+                if let Some(val) = parse_digit(sub_str) {
+                    return Some(val);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn get_last_digit(input: &str) -> Option<char> {
+    for i in (0..input.len()).rev() {
+        for j in i..input.len() {
+            if let Some(sub_str) = input.get(i..j + 1) {
+                if let Some(val) = parse_digit(sub_str) {
+                    return Some(val);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn parse_digit(input: &str) -> Option<char> {
+    if input.is_empty() {
+        return None;
+    }
+
+    if input.len() == 1 && input.parse::<u64>().is_ok() {
+        return input.chars().next();
+    }
+
+    match input.chars().take(3).collect::<String>().as_str() {
+        "one" => return Some('1'),
+        "two" => return Some('2'),
+        "six" => return Some('6'),
+        _ => (),
+    }
+
+    match input.chars().take(4).collect::<String>().as_str() {
+        "four" => return Some('4'),
+        "five" => return Some('5'),
+        "nine" => return Some('9'),
+        _ => (),
+    }
+
+    match input.chars().take(5).collect::<String>().as_str() {
+        "three" => Some('3'),
+        "seven" => Some('7'),
+        "eight" => Some('8'),
+        _ => None,
+    }
+}
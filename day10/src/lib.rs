@@ -0,0 +1,70 @@
+mod part1;
+mod part2;
+
+pub use crate::part1::solve_part_1;
+pub use crate::part2::solve_part_2;
+use common::{Error, Solution};
+use grid::{Direction, Grid, Position};
+
+// the two tiles a pipe connects, where they are on the map
+fn connected_tiles(map: &Grid<char>, position: Position) -> [Option<Position>; 2] {
+    let directions = match map.get(position) {
+        Some('|') => [Direction::North, Direction::South],
+        Some('-') => [Direction::East, Direction::West],
+        Some('L') => [Direction::North, Direction::East],
+        Some('F') => [Direction::South, Direction::East],
+        Some('7') => [Direction::South, Direction::West],
+        Some('J') => [Direction::North, Direction::West],
+        _ => return [None, None],
+    };
+
+    directions.map(|direction| map.step(position, direction))
+}
+
+fn find_starting_position(map: &Grid<char>) -> Result<Position, Error> {
+    map.find(|&c| c == 'S')
+        .ok_or_else(|| Error::invalid_state("There is no starting tile 'S'"))
+}
+
+// the pipes either side of the start, which has to be part of the loop
+fn find_initial_connected_tiles(
+    map: &Grid<char>,
+    starting_position: Position,
+) -> Result<(Position, Position), Error> {
+    let with_connections: Vec<Position> = map
+        .neighbours(starting_position)
+        .filter(|&position| connected_tiles(map, position).contains(&Some(starting_position)))
+        .collect();
+
+    match with_connections[..] {
+        [first, second] => Ok((first, second)),
+        _ => Err(Error::invalid_state(format!(
+            "The starting tile connects to {} pipes instead of 2",
+            with_connections.len()
+        ))),
+    }
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input, |c| match c {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(c),
+            _ => Err(Error::parse(format!("Unknown tile '{c}'"))),
+        })
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Self::Answer, Error> {
+        solve_part_1(map)
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Self::Answer, Error> {
+        solve_part_2(map)
+    }
+}
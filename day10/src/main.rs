@@ -1,13 +1,6 @@
-use crate::part2::solve_part_2;
-use std::fs::read_to_string;
+use day10::Day10;
+use std::process::ExitCode;
 
-mod part1;
-mod part2;
-
-fn main() {
-    let input = read_to_string("input.txt").expect("Failed to read input file");
-
-    let solution = solve_part_2(input);
-
-    println!("{solution}");
+fn main() -> ExitCode {
+    common::run::<Day10>()
 }
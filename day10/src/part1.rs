@@ -1,214 +1,65 @@
-type Position = (usize, usize); // row_num, column_num
-
-trait Navigable
-where
-    Self: Sized,
-{
-    fn north(&self) -> Option<Self>;
-    fn south(&self) -> Option<Self>;
-    fn east(&self) -> Option<Self>;
-    fn west(&self) -> Option<Self>;
-}
+use crate::{connected_tiles, find_initial_connected_tiles, find_starting_position};
+use common::{cancel, Error};
+use grid::Grid;
 
-impl Navigable for Position {
-    fn north(&self) -> Option<Self> {
-        if self.0 > 0 {
-            Some((self.0 - 1, self.1))
-        } else {
-            None
-        }
-    }
+pub fn solve_part_1(map: &Grid<char>) -> Result<usize, Error> {
+    let starting_position = find_starting_position(map)?;
 
-    fn south(&self) -> Option<Self> {
-        Some((self.0 + 1, self.1))
-    }
+    let (first_tile, _) = find_initial_connected_tiles(map, starting_position)?;
 
-    fn east(&self) -> Option<Self> {
-        Some((self.0, self.1 + 1))
-    }
-
-    fn west(&self) -> Option<Self> {
-        if self.1 > 0 {
-            Some((self.0, self.1 - 1))
-        } else {
-            None
-        }
-    }
-}
+    let mut previous_position = starting_position;
+    let mut current_position = first_tile;
+    let mut path_length = 1;
 
-trait Map<T> {
-    fn get_tile(&self, position: Position) -> T;
-}
-
-impl<T> Map<T> for Vec<Vec<T>>
-where
-    T: Copy,
-{
-    fn get_tile(&self, position: Position) -> T {
-        self[position.0][position.1]
-    }
-}
-
-trait ConnectedMap {
-    fn get_connected_tiles(&self, position: Position) -> (Option<Position>, Option<Position>);
-    fn find_starting_position(&self) -> Position;
-    fn find_initial_connected_tiles(&self, starting_position: Position) -> (Position, Position);
-    fn follow_path(
-        &self,
-        starting_position: Position,
-        previous_position: Position,
-        current_position: Position,
-    ) -> usize;
-}
-
-impl ConnectedMap for Vec<Vec<char>> {
-    fn get_connected_tiles(&self, position: Position) -> (Option<Position>, Option<Position>) {
-        let (row_num, column_num) = position;
-        let tile = self.get_tile(position);
-        match tile {
-            '|' => (
-                if row_num > 0 {
-                    Some((row_num - 1, column_num))
-                } else {
-                    None
-                },
-                Some((row_num + 1, column_num)),
-            ),
-            '-' => (
-                if column_num > 0 {
-                    Some((row_num, column_num - 1))
-                } else {
-                    None
-                },
-                Some((row_num, column_num + 1)),
-            ),
-            'L' => (
-                if row_num > 0 {
-                    Some((row_num - 1, column_num))
-                } else {
-                    None
-                },
-                Some((row_num, column_num + 1)),
-            ),
-            'F' => (
-                Some((row_num, column_num + 1)),
-                Some((row_num + 1, column_num)),
-            ),
-            '7' => (
-                if column_num > 0 {
-                    Some((row_num, column_num - 1))
-                } else {
-                    None
-                },
-                Some((row_num + 1, column_num)),
-            ),
-            'J' => (
-                if column_num > 0 {
-                    Some((row_num, column_num - 1))
-                } else {
-                    None
-                },
-                if row_num > 0 {
-                    Some((row_num - 1, column_num))
-                } else {
-                    None
-                },
-            ),
-            _ => (None, None),
-        }
-    }
-
-    fn find_starting_position(&self) -> Position {
-        for (row_num, row) in self.iter().enumerate() {
-            for (column_num, character) in row.iter().enumerate() {
-                if *character == 'S' {
-                    return (row_num, column_num);
-                }
-            }
-        }
-        panic!("Failed to find starting position");
-    }
-
-    fn find_initial_connected_tiles(&self, starting_position: Position) -> (Position, Position) {
-        let with_connections: Vec<Position> = [
-            starting_position.north(),
-            starting_position.south(),
-            starting_position.east(),
-            starting_position.west(),
-        ]
-        .iter()
-        .filter(|&&position| {
-            if let Some(position) = position {
-                let (p1, p2) = self.get_connected_tiles(position);
-                p1 == Some(starting_position) || p2 == Some(starting_position)
-            } else {
-                false
-            }
-        })
+    // a path that loops back on itself short of the start would go round forever, and no loop
+    // can be longer than the map has tiles
+    while let Some(next_position) = connected_tiles(map, current_position)
+        .into_iter()
         .flatten()
-        .copied()
-        .collect();
-
-        if with_connections.len() != 2 {
-            panic!("Oops");
+        .find(|&p| p != starting_position && p != previous_position)
+    {
+        cancel::checkpoint()?;
+
+        if path_length >= map.rows() * map.columns() {
+            return Err(Error::invalid_state(
+                "The loop from the starting tile never closes",
+            ));
         }
 
-        (with_connections[0], with_connections[1])
+        previous_position = current_position;
+        current_position = next_position;
+        path_length += 1;
     }
 
-    fn follow_path(
-        &self,
-        starting_position: Position,
-        previous_position: Position,
-        current_position: Position,
-    ) -> usize {
-        let (t1, t2) = self.get_connected_tiles(current_position);
-
-        let tiles: Vec<Position> = [t1, t2]
-            .iter()
-            .filter_map(|&t| {
-                if t == Some(starting_position) || t == Some(previous_position) {
-                    None
-                } else {
-                    t
-                }
-            })
-            .collect();
-
-        if let Some(next_tile) = tiles.first() {
-            1 + self.follow_path(starting_position, current_position, *next_tile)
-        } else {
-            1
-        }
+    // a dead end, rather than a pipe leading back to the start
+    if !connected_tiles(map, current_position).contains(&Some(starting_position)) {
+        return Err(Error::invalid_state(format!(
+            "The loop is broken at line {}, column {}",
+            current_position.row + 1,
+            current_position.column + 1
+        )));
     }
-}
 
-pub fn solve_part_1(input: String) -> usize {
-    let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    // the start tile closes the loop
+    let loop_length = path_length + 1;
 
-    let starting_position = map.find_starting_position();
-
-    let initial_connected_tiles = map.find_initial_connected_tiles(starting_position);
-
-    let total = 1 + map.follow_path(
-        starting_position,
-        starting_position,
-        initial_connected_tiles.0,
-    );
-
-    total / 2
+    Ok(loop_length / 2)
 }
 
 #[cfg(test)]
 mod test {
     use crate::part1::solve_part_1;
+    use crate::Day10;
+    use common::Solution;
     use std::fs::read_to_string;
 
     #[test]
     fn test_1() {
         let input = read_to_string("test1.txt").expect("Failed to read test file");
 
-        let solution = solve_part_1(input);
+        let map = Day10::parse(&input).expect("Failed to parse test file");
+
+        let solution = solve_part_1(&map).expect("Failed to solve test file");
 
         assert_eq!(solution, 4);
     }
@@ -217,8 +68,29 @@ mod test {
     fn test_2() {
         let input = read_to_string("test2.txt").expect("Failed to read test file");
 
-        let solution = solve_part_1(input);
+        let map = Day10::parse(&input).expect("Failed to parse test file");
+
+        let solution = solve_part_1(&map).expect("Failed to solve test file");
 
         assert_eq!(solution, 8);
     }
+
+    #[test]
+    fn test_broken_loops() {
+        // the pipe east of the start leads nowhere
+        let map = Day10::parse("S-.\n|..\nL..\n").expect("Failed to parse map");
+        let error = solve_part_1(&map).expect_err("Solved a broken loop");
+        assert_eq!(
+            error.to_string(),
+            "Invalid puzzle state: The loop is broken at line 1, column 3"
+        );
+
+        // the 'L' sends the walk back round the pipes east of the start, never reaching it
+        let map = Day10::parse("S-7\n|LJ\n|..\n").expect("Failed to parse map");
+        let error = solve_part_1(&map).expect_err("Solved a loop that never closes");
+        assert_eq!(
+            error.to_string(),
+            "Invalid puzzle state: The loop from the starting tile never closes"
+        );
+    }
 }
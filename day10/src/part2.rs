@@ -1,3 +1,6 @@
+use crate::{find_initial_connected_tiles, find_starting_position};
+use common::Error;
+use grid::{Direction, Grid, Position};
 use std::fmt::{Display, Formatter};
 
 #[derive(Eq, PartialEq)]
@@ -6,417 +9,214 @@ enum RelativeDirection {
     Right,
 }
 
-#[derive(Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Debug)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    pub fn left(&self) -> Direction {
-        match self {
-            Self::North => Self::West,
-            Self::East => Self::North,
-            Self::South => Self::East,
-            Self::West => Self::South,
-        }
-    }
-
-    pub fn right(&self) -> Direction {
-        match self {
-            Self::North => Self::East,
-            Self::East => Self::South,
-            Self::South => Self::West,
-            Self::West => Self::North,
-        }
-    }
-}
-
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-struct Position {
-    row: usize,
-    column: usize,
+struct Heading {
+    position: Position,
     facing: Direction,
 }
 
-impl Position {
+impl Heading {
     pub fn turn_left(&self) -> Self {
-        Position {
-            row: self.row,
-            column: self.column,
-            facing: self.facing.left(),
+        Heading {
+            position: self.position,
+            facing: self.facing.turn_left(),
         }
     }
 
     pub fn turn_right(&self) -> Self {
-        Position {
-            row: self.row,
-            column: self.column,
-            facing: self.facing.right(),
-        }
-    }
-
-    pub fn move_forwards(&self) -> Self {
-        match self.facing {
-            Direction::North => self.north(),
-            Direction::South => self.south(),
-            Direction::East => self.east(),
-            Direction::West => self.west(),
-        }
-        .unwrap()
-    }
-
-    fn north(&self) -> Option<Self> {
-        if self.row > 0 {
-            Some(Position {
-                row: self.row - 1,
-                column: self.column,
-                facing: self.facing,
-            })
-        } else {
-            None
-        }
-    }
-
-    fn south(&self) -> Option<Self> {
-        Some(Position {
-            row: self.row + 1,
-            column: self.column,
-            facing: self.facing,
-        })
-    }
-
-    fn east(&self) -> Option<Self> {
-        Some(Position {
-            row: self.row,
-            column: self.column + 1,
-            facing: self.facing,
-        })
-    }
-
-    fn west(&self) -> Option<Self> {
-        if self.column > 0 {
-            Some(Position {
-                row: self.row,
-                column: self.column - 1,
-                facing: self.facing,
-            })
-        } else {
-            None
-        }
-    }
-
-    fn left(&self) -> Option<Self> {
-        match self.facing {
-            Direction::North => self.west(),
-            Direction::South => self.east(),
-            Direction::East => self.north(),
-            Direction::West => self.south(),
+        Heading {
+            position: self.position,
+            facing: self.facing.turn_right(),
         }
     }
-
-    fn right(&self) -> Option<Self> {
-        match self.facing {
-            Direction::North => self.east(),
-            Direction::South => self.west(),
-            Direction::East => self.south(),
-            Direction::West => self.north(),
-        }
-    }
-
-    fn is_same_tile(&self, other: &Option<Position>) -> bool {
-        if let Some(other) = other {
-            self.row == other.row && self.column == other.column
-        } else {
-            false
-        }
-    }
-}
-
-trait Map<T> {
-    fn get_tile(&self, position: &Position) -> T;
-}
-
-impl<T> Map<T> for Vec<Vec<T>>
-where
-    T: Copy,
-{
-    fn get_tile(&self, position: &Position) -> T {
-        self[position.row][position.column]
-    }
-}
-
-trait ConnectedMap {
-    fn get_connected_tiles(&self, position: &Position) -> (Option<Position>, Option<Position>);
-    fn find_initial_connected_tiles(&self, starting_position: Position) -> (Position, Position);
-}
-
-impl ConnectedMap for Vec<Vec<char>> {
-    fn get_connected_tiles(&self, position: &Position) -> (Option<Position>, Option<Position>) {
-        let tile = self.get_tile(position);
-        match tile {
-            '|' => (position.north(), position.south()),
-            '-' => (position.east(), position.west()),
-            'L' => (position.north(), position.east()),
-            'F' => (position.south(), position.east()),
-            '7' => (position.south(), position.west()),
-            'J' => (position.north(), position.west()),
-            _ => (None, None),
-        }
-    }
-
-    fn find_initial_connected_tiles(&self, starting_position: Position) -> (Position, Position) {
-        let with_connections: Vec<Position> = [
-            starting_position.north(),
-            starting_position.south(),
-            starting_position.east(),
-            starting_position.west(),
-        ]
-        .into_iter()
-        .filter(|position| {
-            if let Some(position) = position {
-                let (p1, p2) = self.get_connected_tiles(position);
-                p1 == Some(starting_position) || p2 == Some(starting_position)
-            } else {
-                false
-            }
-        })
-        .flatten()
-        .collect();
-
-        if with_connections.len() != 2 {
-            panic!("Oops");
-        }
-
-        (with_connections[0], with_connections[1])
-    }
 }
 
 #[derive(Debug)]
 struct WorldMap {
-    pipe_tiles: Vec<Vec<char>>,
-    path_tiles: Vec<Vec<char>>,
-    start: Position,
+    pipe_tiles: Grid<char>,
+    path_tiles: Grid<char>,
+    start: Heading,
 }
 
 impl WorldMap {
-    pub fn new(input: &str) -> Self {
-        let mut pipe_tiles = input.lines().map(|line| line.chars().collect()).collect();
+    pub fn new(pipe_tiles: Grid<char>) -> Result<Self, Error> {
+        let path_tiles = Grid::filled(pipe_tiles.rows(), pipe_tiles.columns(), ' ');
 
-        let path_tiles = input
-            .lines()
-            .map(|line| vec![' '; line.chars().count()])
-            .collect();
+        let start = Self::find_starting_heading(&pipe_tiles)?;
 
-        let start = Self::find_starting_position(&mut pipe_tiles);
-
-        Self {
+        Ok(Self {
             pipe_tiles,
             path_tiles,
             start,
-        }
+        })
     }
 
-    pub fn find_starting_position(pipe_tiles: &mut Vec<Vec<char>>) -> Position {
-        for (row, row_tiles) in pipe_tiles.iter().enumerate() {
-            for (column, c) in row_tiles.iter().enumerate() {
-                if *c == 'S' {
-                    let mut position = Position {
-                        row,
-                        column,
-                        facing: Direction::North,
-                    };
-
-                    let (option1, option2) = pipe_tiles.find_initial_connected_tiles(position);
-
-                    let mut turns_since_last_option = 10;
-
-                    // this loop ensures the start pipe is either straight or a right-hand turn
-                    loop {
-                        let test_position = position.move_forwards();
-                        if option1.is_same_tile(&Some(test_position))
-                            || option2.is_same_tile(&Some(test_position))
-                        {
-                            if turns_since_last_option > 2 {
-                                turns_since_last_option = 0;
-                            } else {
-                                break;
-                            }
-                        }
-                        turns_since_last_option += 1;
-                        position = position.turn_right();
-                    }
-
-                    return position;
+    pub fn find_starting_heading(pipe_tiles: &Grid<char>) -> Result<Heading, Error> {
+        let position = find_starting_position(pipe_tiles)?;
+
+        let (option1, option2) = find_initial_connected_tiles(pipe_tiles, position)?;
+
+        let mut heading = Heading {
+            position,
+            facing: Direction::North,
+        };
+
+        let mut turns_since_last_option = 10;
+
+        // this loop ensures the start pipe is either straight or a right-hand turn
+        loop {
+            let test_position = pipe_tiles.step(position, heading.facing);
+            if test_position == Some(option1) || test_position == Some(option2) {
+                if turns_since_last_option > 2 {
+                    turns_since_last_option = 0;
+                } else {
+                    break;
                 }
             }
+            turns_since_last_option += 1;
+            heading = heading.turn_right();
         }
-        panic!("Failed to find starting position");
+
+        Ok(heading)
     }
 
     fn turn_and_flood(
         &mut self,
-        position: Position,
+        heading: Heading,
         left_turns: u16,
         right_turns: u16,
-    ) -> RelativeDirection {
-        let flood_direction = match self.pipe_tiles[position.row][position.column] {
-            '|' => self.move_and_flood(position, left_turns, right_turns),
-            '-' => self.move_and_flood(position, left_turns, right_turns),
-            'L' => match position.facing {
-                Direction::West => {
-                    self.move_and_flood(position.turn_right(), left_turns, right_turns + 1)
-                }
-                Direction::South => {
-                    self.move_and_flood(position.turn_left(), left_turns + 1, right_turns)
-                }
-                _ => panic!("Oops"),
-            },
-            'F' => match position.facing {
-                Direction::West => {
-                    self.move_and_flood(position.turn_left(), left_turns + 1, right_turns)
-                }
-                Direction::North => {
-                    self.move_and_flood(position.turn_right(), left_turns, right_turns + 1)
-                }
-                _ => panic!("Oops"),
-            },
-            '7' => match position.facing {
-                Direction::East => {
-                    self.move_and_flood(position.turn_right(), left_turns, right_turns + 1)
-                }
-                Direction::North => {
-                    self.move_and_flood(position.turn_left(), left_turns + 1, right_turns)
-                }
-                _ => panic!("Oops"),
-            },
-            'J' => match position.facing {
-                Direction::East => {
-                    self.move_and_flood(position.turn_left(), left_turns + 1, right_turns)
-                }
-                Direction::South => {
-                    self.move_and_flood(position.turn_right(), left_turns, right_turns + 1)
-                }
-                _ => panic!("Oops"),
-            },
-            _ => panic!("Oops"),
-        };
+    ) -> Result<RelativeDirection, Error> {
+        let tile = self.pipe_tiles[heading.position];
+        let flood_direction = match (tile, heading.facing) {
+            ('|', _) | ('-', _) => self.move_and_flood(heading, left_turns, right_turns),
+            ('L', Direction::West)
+            | ('F', Direction::North)
+            | ('7', Direction::East)
+            | ('J', Direction::South) => {
+                self.move_and_flood(heading.turn_right(), left_turns, right_turns + 1)
+            }
+            ('L', Direction::South)
+            | ('F', Direction::West)
+            | ('7', Direction::North)
+            | ('J', Direction::East) => {
+                self.move_and_flood(heading.turn_left(), left_turns + 1, right_turns)
+            }
+            _ => Err(Error::invalid_state(format!(
+                "The loop is broken at line {}, column {}: can't enter '{tile}' heading {:?}",
+                heading.position.row + 1,
+                heading.position.column + 1,
+                heading.facing
+            ))),
+        }?;
         // flood the place
-        let position_to_flood = match flood_direction {
-            RelativeDirection::Left => position.left(),
-            RelativeDirection::Right => position.right(),
-        };
-        if self.path_tiles[position_to_flood.unwrap().row][position_to_flood.unwrap().column] == ' '
-        {
-            self.flood(position_to_flood.unwrap());
-        };
-        flood_direction
+        self.flood_beside(heading, &flood_direction);
+        Ok(flood_direction)
     }
 
     fn move_and_flood(
         &mut self,
-        position: Position,
+        heading: Heading,
         left_turns: u16,
         right_turns: u16,
-    ) -> RelativeDirection {
-        let new_position = position.move_forwards();
-        self.path_tiles[new_position.row][new_position.column] = '+';
+    ) -> Result<RelativeDirection, Error> {
+        let new_heading = Heading {
+            position: self
+                .pipe_tiles
+                .step(heading.position, heading.facing)
+                .ok_or_else(|| Error::invalid_state("The loop runs off the edge of the map"))?,
+            facing: heading.facing,
+        };
+        self.path_tiles[new_heading.position] = '+';
 
-        let flood_direction = if new_position.is_same_tile(&Some(self.start)) {
+        let flood_direction = if new_heading.position == self.start.position {
             if left_turns > right_turns {
                 RelativeDirection::Left
             } else {
                 RelativeDirection::Right
             }
         } else {
-            self.turn_and_flood(new_position, left_turns, right_turns)
+            self.turn_and_flood(new_heading, left_turns, right_turns)?
         };
 
         // flood the place
-        let new_position_to_flood = match flood_direction {
-            RelativeDirection::Left => new_position.left(),
-            RelativeDirection::Right => new_position.right(),
-        };
-        if self.path_tiles[new_position_to_flood.unwrap().row]
-            [new_position_to_flood.unwrap().column]
-            == ' '
-        {
-            self.flood(new_position_to_flood.unwrap());
-        };
-        let old_position_to_flood = match flood_direction {
-            RelativeDirection::Left => position.left(),
-            RelativeDirection::Right => position.right(),
-        };
-        if self.path_tiles[old_position_to_flood.unwrap().row]
-            [old_position_to_flood.unwrap().column]
-            == ' '
-        {
-            self.flood(old_position_to_flood.unwrap());
-        };
+        self.flood_beside(new_heading, &flood_direction);
+        self.flood_beside(heading, &flood_direction);
+
+        Ok(flood_direction)
+    }
 
-        flood_direction
+    fn flood_beside(&mut self, heading: Heading, flood_direction: &RelativeDirection) {
+        let side = match flood_direction {
+            RelativeDirection::Left => heading.facing.turn_left(),
+            RelativeDirection::Right => heading.facing.turn_right(),
+        };
+        if let Some(position) = self.path_tiles.step(heading.position, side) {
+            self.flood(position);
+        }
     }
 
     fn flood(&mut self, position: Position) {
-        if self.path_tiles[position.row][position.column] == ' ' {
-            self.path_tiles[position.row][position.column] = '#';
-            self.flood(position.north().unwrap());
-            self.flood(position.south().unwrap());
-            self.flood(position.east().unwrap());
-            self.flood(position.west().unwrap());
+        let mut to_flood = vec![position];
+
+        while let Some(position) = to_flood.pop() {
+            if self.path_tiles[position] == ' ' {
+                self.path_tiles[position] = '#';
+                to_flood.extend(self.path_tiles.neighbours(position));
+            }
         }
     }
 }
 
 impl Display for WorldMap {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for row in &self.path_tiles {
-            writeln!(f, "{}", String::from_iter(row))?;
-        }
-        Ok(())
+        writeln!(f, "{}", self.path_tiles)
     }
 }
 
-pub fn solve_part_2(input: String) -> usize {
+pub fn solve_part_2(map: &Grid<char>) -> Result<usize, Error> {
     // build a map with JUST the path, tracking turns
     // if more total right turns than left, colour left, otherwise right
 
-    let mut world_map = WorldMap::new(&input);
+    let mut world_map = WorldMap::new(map.clone())?;
 
-    world_map.move_and_flood(world_map.start, 0, 0);
+    world_map.move_and_flood(world_map.start, 0, 0)?;
 
-    println!("{world_map}");
+    tracing::debug!("path and flooded tiles:\n{world_map}");
 
-    world_map
+    Ok(world_map
         .path_tiles
+        .cells()
         .iter()
-        .flatten()
         .filter(|&&c| c == '#')
-        .count()
+        .count())
 }
 
 #[cfg(test)]
 mod test {
     use crate::part2::solve_part_2;
+    use crate::Day10;
+    use common::Solution;
     use std::fs::read_to_string;
 
     #[test]
     fn test_1() {
         let input = read_to_string("test1.txt").expect("Failed to read test file");
 
-        let solution = solve_part_2(input);
+        let map = Day10::parse(&input).expect("Failed to parse test file");
 
-        assert_eq!(solution, 4);
+        let solution = solve_part_2(&map).expect("Failed to solve test file");
+
+        assert_eq!(solution, 1);
     }
 
     #[test]
     fn test_2() {
         let input = read_to_string("test2.txt").expect("Failed to read test file");
 
-        let solution = solve_part_2(input);
+        let map = Day10::parse(&input).expect("Failed to parse test file");
+
+        let solution = solve_part_2(&map).expect("Failed to solve test file");
 
-        assert_eq!(solution, 8);
+        assert_eq!(solution, 1);
     }
 }
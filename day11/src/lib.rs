@@ -0,0 +1,111 @@
+use common::{Error, Solution};
+use grid::Grid;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Galaxy {
+    pub row: usize,
+    pub column: usize,
+}
+
+fn calculate_distance(a: &Galaxy, b: &Galaxy) -> u64 {
+    (a.row as i64 - b.row as i64).unsigned_abs()
+        + (a.column as i64 - b.column as i64).unsigned_abs()
+}
+
+pub fn sum_shortest_distances(galaxies: &[Galaxy]) -> u64 {
+    if let Some((this_galaxy, remaining_galaxies)) = galaxies.split_last() {
+        remaining_galaxies
+            .iter()
+            .map(|other_galaxy| calculate_distance(this_galaxy, other_galaxy))
+            .sum::<u64>()
+            + sum_shortest_distances(remaining_galaxies)
+    } else {
+        0
+    }
+}
+
+pub struct Universe {
+    pub image: Grid<char>,
+    pub empty_rows: HashSet<usize>,
+    pub empty_columns: HashSet<usize>,
+}
+
+impl Universe {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let image = Grid::parse(input, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(Error::parse(format!("Unknown pixel '{c}'"))),
+        })?;
+
+        let empty_rows: HashSet<usize> = image
+            .iter_rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|&c| c == '.'))
+            .map(|(row_index, _)| row_index)
+            .collect();
+
+        let empty_columns: HashSet<usize> = image
+            .iter_columns()
+            .enumerate()
+            .filter_map(|(column_index, mut column)| {
+                column.all(|&c| c == '.').then_some(column_index)
+            })
+            .collect();
+
+        Ok(Self {
+            image,
+            empty_rows,
+            empty_columns,
+        })
+    }
+
+    fn expand_axis(empty: &HashSet<usize>, length: usize, expansion: usize) -> Vec<usize> {
+        (0..length)
+            .scan(0, |expanded, i| {
+                let this = *expanded;
+                *expanded += if empty.contains(&i) { expansion } else { 1 };
+                Some(this)
+            })
+            .collect()
+    }
+
+    pub fn expand(&self, expansion: usize) -> Vec<Galaxy> {
+        let rows = Self::expand_axis(&self.empty_rows, self.image.rows(), expansion);
+        let columns = Self::expand_axis(&self.empty_columns, self.image.columns(), expansion);
+
+        self.image
+            .iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|(position, _)| Galaxy {
+                row: rows[position.row],
+                column: columns[position.column],
+            })
+            .collect()
+    }
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Universe;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Universe::new(input)
+    }
+
+    fn part1(universe: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let galaxies = universe.expand(2);
+
+        Ok(sum_shortest_distances(&galaxies))
+    }
+
+    fn part2(universe: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let galaxies = universe.expand(1_000_000);
+
+        Ok(sum_shortest_distances(&galaxies))
+    }
+}
@@ -0,0 +1,210 @@
+use ascii::{AsAsciiStr, AsciiChar, AsciiStr, AsciiString};
+use common::{parse, Error, Solution};
+use dashmap::DashMap;
+use rayon::prelude::*;
+use std::fmt::{Display, Formatter};
+
+// the counts are None once they're past what fits in a usize
+pub type Cache = DashMap<(AsciiString, Vec<usize>), Option<usize>>;
+
+// called when the last character was a '#' or equivalent
+pub fn consume_group(
+    chars: &[AsciiChar],
+    group_size: usize,
+    remaining_groups: &[usize],
+    cache: &Cache,
+) -> Option<usize> {
+    if group_size == 0 {
+        match chars.split_first() {
+            Some((AsciiChar::Dot | AsciiChar::Question, remaining_chars)) => {
+                // dot or question mark -- we're done consuming '#' for now
+                consume_space(remaining_chars, remaining_groups, cache)
+            }
+            Some((AsciiChar::Hash, _)) => {
+                // we've hit a hash, but run out of group. no dice
+                Some(0)
+            }
+            None => {
+                if remaining_groups.is_empty() {
+                    // group has been consumed, no more input and no more groups
+                    Some(1)
+                } else {
+                    // group has been consumed, no more input but we have more groups!
+                    Some(0)
+                }
+            }
+            Some(_) => unreachable!("Records only contain '.', '#' and '?'"),
+        }
+    } else {
+        match chars.split_first() {
+            Some((AsciiChar::Hash | AsciiChar::Question, remaining_chars)) => {
+                consume_group(remaining_chars, group_size - 1, remaining_groups, cache)
+            }
+            Some((AsciiChar::Dot, _)) | None => {
+                // expected more, but group ended
+                Some(0)
+            }
+            Some(_) => unreachable!("Records only contain '.', '#' and '?'"),
+        }
+    }
+}
+
+pub fn consume_space(chars: &[AsciiChar], groups: &[usize], cache: &Cache) -> Option<usize> {
+    match groups.split_first() {
+        // short circuit if we have no groups
+        None => {
+            if chars.contains(&AsciiChar::Hash) {
+                // no more groups, but there's a '#' somewhere
+                Some(0)
+            } else {
+                // no more groups, no more hashes (all remaining question marks must be '.')
+                Some(1)
+            }
+        }
+        Some((group_size, remaining_groups)) => {
+            match chars.split_first() {
+                Some((AsciiChar::Dot, remaining_chars)) => {
+                    consume_space(remaining_chars, groups, cache)
+                }
+                Some((AsciiChar::Hash, remaining_chars)) => {
+                    consume_group(remaining_chars, *group_size - 1, remaining_groups, cache)
+                }
+                Some((AsciiChar::Question, remaining_chars)) => {
+                    let cache_key = (AsciiString::from(remaining_chars), Vec::from(groups));
+                    match cache.get(&cache_key) {
+                        Some(val) => *val,
+                        None => {
+                            let result = consume_group(
+                                remaining_chars,
+                                *group_size - 1,
+                                remaining_groups,
+                                cache,
+                            )
+                            .zip(consume_space(remaining_chars, groups, cache))
+                            .and_then(|(hash, dot)| hash.checked_add(dot));
+                            cache.insert(cache_key, result);
+                            result
+                        }
+                    }
+                }
+                None => Some(0), // we expect a group, but we've run over the end of the string
+                Some(_) => unreachable!("Records only contain '.', '#' and '?'"),
+            }
+        }
+    }
+}
+
+pub struct Record {
+    pub springs: AsciiString,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    pub fn parse(line: &str) -> Result<Self, Error> {
+        let (springs, groups) = line
+            .split_once(' ')
+            .ok_or_else(|| Error::parse("Expected springs and groups separated by a space"))?;
+
+        if let Some((i, c)) = springs
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+        {
+            return Err(Error::parse(format!("Unknown spring '{c}'")).at_column(i + 1));
+        }
+
+        let springs = springs
+            .as_ascii_str()
+            .map_err(|_| Error::parse("Springs are not ASCII"))?
+            .to_ascii_string();
+
+        let groups: Vec<usize> = groups
+            .split(',')
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+
+        if groups.contains(&0) {
+            return Err(Error::parse("Groups must have at least one spring"));
+        }
+
+        Ok(Self { springs, groups })
+    }
+
+    pub fn unfold(&self) -> Self {
+        let folded_record: &AsciiStr = &self.springs;
+
+        let mut unfolded_record: AsciiString =
+            AsciiString::with_capacity(folded_record.len() * 5 + 4);
+        unfolded_record.extend(folded_record);
+        for _ in 0..4 {
+            unfolded_record.push(AsciiChar::Question);
+            unfolded_record.extend(folded_record);
+        }
+
+        let mut unfolded_groups: Vec<usize> = Vec::with_capacity(self.groups.len() * 5);
+        for _ in 0..5 {
+            unfolded_groups.extend(&self.groups);
+        }
+
+        Self {
+            springs: unfolded_record,
+            groups: unfolded_groups,
+        }
+    }
+
+    pub fn possible_combinations(&self, cache: &Cache) -> Result<usize, Error> {
+        consume_space(self.springs.as_slice(), &self.groups, cache).ok_or_else(|| {
+            Error::invalid_state(format!("{self} has more arrangements than fit in a usize"))
+        })
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let groups: Vec<String> = self.groups.iter().map(|g| g.to_string()).collect();
+
+        write!(f, "{} {}", self.springs, groups.join(","))
+    }
+}
+
+fn sum(counts: impl ParallelIterator<Item = Result<usize, Error>>) -> Result<usize, Error> {
+    counts.try_reduce(
+        || 0,
+        |a, b| {
+            a.checked_add(b)
+                .ok_or_else(|| Error::invalid_state("The sum doesn't fit in a usize"))
+        },
+    )
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<Record>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse::lines(input, Record::parse)
+    }
+
+    fn part1(records: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let cache: Cache = DashMap::new();
+
+        sum(records
+            .par_iter()
+            .map(|record| record.possible_combinations(&cache)))
+    }
+
+    fn part2(records: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let cache: Cache = DashMap::new();
+
+        sum(records.par_iter().map(|record| {
+            let possible_combinations = record.unfold().possible_combinations(&cache)?;
+
+            tracing::debug!(%record, possible_combinations);
+
+            Ok(possible_combinations)
+        }))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::Reflection::{Horizontal, Vertical};
+use common::{parse, Error, Solution};
+use grid::{Grid, Position};
+
+#[derive(Copy, Clone)]
+pub enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+// a mirror between rows `mirror` and `mirror + 1`, skipping the one already found
+fn find_horizontal_reflection(pattern: &Grid<char>, old: Option<usize>) -> Option<usize> {
+    (0..pattern.rows().saturating_sub(1)).find(|&mirror| {
+        old != Some(mirror)
+            && (0..=mirror)
+                .rev()
+                .zip(mirror + 1..pattern.rows())
+                .all(|(top, bottom)| pattern.row(top) == pattern.row(bottom))
+    })
+}
+
+pub fn find_reflection(pattern: &Grid<char>, old: Option<Reflection>) -> Option<Reflection> {
+    let old_horizontal = match old {
+        Some(Horizontal(val)) => Some(val),
+        _ => None,
+    };
+    let old_vertical = match old {
+        Some(Vertical(val)) => Some(val),
+        _ => None,
+    };
+
+    if let Some(val) = find_horizontal_reflection(pattern, old_horizontal) {
+        Some(Horizontal(val))
+    } else {
+        find_horizontal_reflection(&pattern.transpose(), old_vertical).map(Vertical)
+    }
+}
+
+fn summarise(reflection: Reflection) -> usize {
+    match reflection {
+        Horizontal(val) => (val + 1) * 100,
+        Vertical(val) => val + 1,
+    }
+}
+
+fn find_smudged_reflection(pattern: &Grid<char>) -> Option<Reflection> {
+    let unadulterated_solution = find_reflection(pattern, None)?;
+
+    pattern.positions().find_map(|position: Position| {
+        let mut modified_pattern = pattern.clone();
+        modified_pattern[position] = match pattern[position] {
+            '.' => '#',
+            _ => '.',
+        };
+
+        find_reflection(&modified_pattern, Some(unadulterated_solution))
+    })
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Grid<char>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse::paragraphs(input, |pattern| {
+            let pattern = Grid::parse(pattern, |c| match c {
+                '.' | '#' => Ok(c),
+                _ => Err(Error::parse(format!("Unknown terrain '{c}'"))),
+            })?;
+
+            if pattern.is_empty() {
+                return Err(Error::parse("Expected a pattern"));
+            }
+
+            Ok(pattern)
+        })
+    }
+
+    fn part1(patterns: &Self::Parsed) -> Result<Self::Answer, Error> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                find_reflection(pattern, None)
+                    .map(summarise)
+                    .ok_or(Error::NoSolution)
+            })
+            .sum()
+    }
+
+    fn part2(patterns: &Self::Parsed) -> Result<Self::Answer, Error> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                find_smudged_reflection(pattern)
+                    .map(summarise)
+                    .ok_or(Error::NoSolution)
+            })
+            .sum()
+    }
+}
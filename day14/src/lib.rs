@@ -0,0 +1,228 @@
+use common::repl::{self, Command, Repl};
+use common::{cycle, Error, Solution};
+use grid::{Direction, Grid, Position};
+use std::fmt::{Display, Formatter, Write};
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+pub enum Tile {
+    SquareRock,
+    RoundRock,
+    Empty,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::SquareRock),
+            'O' => Ok(Self::RoundRock),
+            '.' => Ok(Self::Empty),
+            _ => Err(Error::parse(format!("Unknown tile '{value}'"))),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SquareRock => f.write_char('#'),
+            Self::RoundRock => f.write_char('O'),
+            Self::Empty => f.write_char('.'),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Dish {
+    pub map: Grid<Tile>,
+}
+
+impl Display for Dish {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+impl Dish {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let map = Grid::parse(input, Tile::try_from)?;
+
+        Ok(Self { map })
+    }
+
+    // rolls every round rock as far as it will go
+    pub fn tilt(&mut self, direction: Direction) {
+        let mut positions: Vec<Position> = self.map.positions().collect();
+
+        // rocks nearest the edge being tilted towards have to settle first
+        if matches!(direction, Direction::South | Direction::East) {
+            positions.reverse();
+        }
+
+        for position in positions {
+            if self.map[position] != Tile::RoundRock {
+                continue;
+            }
+
+            let mut resting_place = position;
+
+            while let Some(next) = self
+                .map
+                .step(resting_place, direction)
+                .filter(|&next| self.map[next] == Tile::Empty)
+            {
+                resting_place = next;
+            }
+
+            self.map[position] = Tile::Empty;
+            self.map[resting_place] = Tile::RoundRock;
+        }
+    }
+
+    // the dish's states repeat long before a billion spins
+    pub fn spin(&mut self, times: usize) -> Result<(), Error> {
+        let (cycle, mut states) = cycle::hashed(self, Dish::spun)?;
+
+        *self = states.swap_remove(cycle.reduce(times));
+
+        Ok(())
+    }
+
+    // one tilt each way, north first and then anticlockwise
+    pub fn spin_cycle(&mut self) {
+        for direction in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            self.tilt(direction);
+        }
+    }
+
+    fn spun(&self) -> Self {
+        let mut dish = self.clone();
+        dish.spin_cycle();
+        dish
+    }
+
+    pub fn total_load(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::RoundRock)
+            .map(|(position, _)| self.map.rows() - position.row)
+            .sum()
+    }
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Dish;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Dish::new(input)
+    }
+
+    fn part1(dish: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let mut dish = dish.clone();
+
+        dish.tilt(Direction::North);
+
+        Ok(dish.total_load())
+    }
+
+    fn part2(dish: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let mut dish = dish.clone();
+
+        dish.spin(1_000_000_000)?;
+
+        Ok(dish.total_load())
+    }
+}
+
+// more cycles than that are too many to read
+const MAX_STEP: usize = 10_000;
+
+// the dish as it's being explored, and how many spin cycles it's been through
+pub struct Exploration {
+    pub dish: Dish,
+    pub cycles: usize,
+}
+
+impl Repl for Day14 {
+    type State = Exploration;
+
+    fn start(dish: Self::Parsed) -> Result<Self::State, Error> {
+        Ok(Exploration { dish, cycles: 0 })
+    }
+
+    fn commands() -> Vec<Command<Self::State>> {
+        vec![
+            Command {
+                name: "step",
+                arguments: "[CYCLES]",
+                help: "Run spin cycles, one unless told how many, and show the load after each",
+                run: |exploration, arguments| {
+                    let cycles = if arguments.is_empty() {
+                        1
+                    } else {
+                        repl::argument(arguments, 0, "a number of cycles")?
+                    };
+                    if cycles > MAX_STEP {
+                        return Err(format!(
+                            "Expected at most {MAX_STEP} cycles, found {cycles}"
+                        ));
+                    }
+
+                    let mut loads = Vec::new();
+                    for _ in 0..cycles {
+                        exploration.dish.spin_cycle();
+                        exploration.cycles += 1;
+                        loads.push(format!(
+                            "cycle {}: load {}",
+                            exploration.cycles,
+                            exploration.dish.total_load()
+                        ));
+                    }
+
+                    Ok(loads.join("\n"))
+                },
+            },
+            Command {
+                name: "tilt",
+                arguments: "DIRECTION",
+                help: "Tilt the dish north, east, south or west",
+                run: |exploration, arguments| {
+                    let direction = match arguments.first().copied() {
+                        Some("north") => Direction::North,
+                        Some("east") => Direction::East,
+                        Some("south") => Direction::South,
+                        Some("west") => Direction::West,
+                        _ => return Err("Expected north, east, south or west".to_string()),
+                    };
+
+                    exploration.dish.tilt(direction);
+                    Ok(format!("load {}", exploration.dish.total_load()))
+                },
+            },
+            Command {
+                name: "show",
+                arguments: "",
+                help: "Show the dish",
+                run: |exploration, _| {
+                    Ok(format!(
+                        "{}\nafter {} cycles, load {}",
+                        exploration.dish,
+                        exploration.cycles,
+                        exploration.dish.total_load()
+                    ))
+                },
+            },
+        ]
+    }
+}
@@ -0,0 +1,215 @@
+use ascii::{AsAsciiStr, AsciiChar, AsciiStr, AsciiString};
+use common::repl::{self, Command, Repl};
+use common::{Error, Solution};
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+
+pub struct Lens {
+    pub label: AsciiString,
+    pub focal_length: usize,
+}
+
+impl Lens {
+    fn new(label: AsciiString, focal_length: usize) -> Self {
+        Self {
+            label,
+            focal_length,
+        }
+    }
+}
+
+pub struct LensBox {
+    pub id: usize,
+    pub lenses: VecDeque<Lens>,
+}
+
+impl LensBox {
+    fn new(id: usize) -> Self {
+        Self {
+            id,
+            lenses: VecDeque::new(),
+        }
+    }
+
+    fn insert_lens(&mut self, label: &AsciiStr, focal_length: usize) {
+        if let Some(lens) = self.lenses.iter_mut().find(|lens| lens.label == label) {
+            lens.focal_length = focal_length;
+        } else {
+            self.lenses
+                .push_back(Lens::new(label.to_ascii_string(), focal_length));
+        }
+    }
+
+    fn remove_lens(&mut self, label: &AsciiStr) {
+        for i in 0..self.lenses.len() {
+            if self.lenses[i].label == label {
+                self.lenses.remove(i);
+                break;
+            }
+        }
+    }
+
+    fn calculate_focusing_power(&self) -> usize {
+        self.lenses
+            .iter()
+            .enumerate()
+            .map(|(i, Lens { focal_length, .. })| (1 + self.id) * (i + 1) * focal_length)
+            .sum()
+    }
+}
+
+impl Display for LensBox {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Box {}:", self.id)?;
+
+        for lens in &self.lenses {
+            write!(f, " [{} {}]", lens.label, lens.focal_length)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn hash(s: &AsciiStr) -> usize {
+    s.chars().fold(0, |acc, character| {
+        (acc + character.as_byte() as usize) * 17 % 256
+    })
+}
+
+pub enum Operation {
+    Remove,
+    Insert(usize),
+}
+
+pub fn parse_step(step: &AsciiStr) -> Result<(&AsciiStr, Operation), Error> {
+    let (label, operation) = match step.as_slice() {
+        [label @ .., AsciiChar::Minus] => (label, Operation::Remove),
+        [label @ .., AsciiChar::Equal, digit] if digit.is_ascii_digit() && *digit != '0' => (
+            label,
+            Operation::Insert(digit.as_byte() as usize - b'0' as usize),
+        ),
+        _ => {
+            return Err(Error::parse(format!(
+                "Expected '<label>-' or '<label>=<focal length>', found '{step}'"
+            )))
+        }
+    };
+
+    if label.is_empty() || !label.iter().all(|character| character.is_lowercase()) {
+        return Err(Error::parse(format!(
+            "Invalid label '{}'",
+            <&AsciiStr>::from(label)
+        )));
+    }
+
+    Ok((label.into(), operation))
+}
+
+pub fn arrange_lenses(steps: &[AsciiString]) -> Result<Vec<LensBox>, Error> {
+    let mut boxes: Vec<LensBox> = (0..256).map(LensBox::new).collect();
+
+    for step in steps {
+        let (label, operation) = parse_step(step)?;
+        let hash = hash(label);
+
+        match operation {
+            Operation::Remove => {
+                tracing::debug!(%label, lens_box = hash, "remove");
+                boxes[hash].remove_lens(label)
+            }
+            Operation::Insert(focal_length) => {
+                tracing::debug!(%label, lens_box = hash, focal_length, "insert");
+                boxes[hash].insert_lens(label, focal_length)
+            }
+        }
+    }
+
+    Ok(boxes)
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<AsciiString>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let input = input
+            .trim_end()
+            .as_ascii_str()
+            .map_err(|_| Error::parse("Input is not ASCII"))?;
+
+        let mut column = 1;
+
+        input
+            .split(AsciiChar::Comma)
+            .map(|step| {
+                parse_step(step).map_err(|e| e.at(1, column))?;
+                column += step.len() + 1;
+                Ok(step.to_ascii_string())
+            })
+            .collect()
+    }
+
+    fn part1(steps: &Self::Parsed) -> Result<Self::Answer, Error> {
+        Ok(steps.iter().map(|step| hash(step)).sum())
+    }
+
+    fn part2(steps: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let boxes = arrange_lenses(steps)?;
+
+        Ok(boxes.iter().map(|b| b.calculate_focusing_power()).sum())
+    }
+}
+
+impl Repl for Day15 {
+    // the boxes once every step has been followed
+    type State = Vec<LensBox>;
+
+    fn start(steps: Self::Parsed) -> Result<Self::State, Error> {
+        arrange_lenses(&steps)
+    }
+
+    fn commands() -> Vec<Command<Self::State>> {
+        vec![
+            Command {
+                name: "box",
+                arguments: "N",
+                help: "Show the lenses in a box, front to back",
+                run: |boxes, arguments| {
+                    let id: usize = repl::argument(arguments, 0, "a box from 0 to 255")?;
+                    let lens_box = boxes
+                        .get(id)
+                        .ok_or_else(|| format!("There is no box {id}"))?;
+
+                    Ok(lens_box.to_string())
+                },
+            },
+            Command {
+                name: "boxes",
+                arguments: "",
+                help: "Show every box holding lenses",
+                run: |boxes, _| {
+                    let boxes: Vec<String> = boxes
+                        .iter()
+                        .filter(|lens_box| !lens_box.lenses.is_empty())
+                        .map(LensBox::to_string)
+                        .collect();
+
+                    Ok(boxes.join("\n"))
+                },
+            },
+            Command {
+                name: "hash",
+                arguments: "LABEL",
+                help: "Show which box a label's lenses go in",
+                run: |_, arguments| {
+                    let label: AsciiString = repl::argument(arguments, 0, "a label")?;
+                    Ok(hash(&label).to_string())
+                },
+            },
+        ]
+    }
+}
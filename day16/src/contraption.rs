@@ -1,262 +1,144 @@
 use crate::tile::{Orientation, Tile, Tilt};
-use std::collections::HashSet;
+use common::search::{self, DenseVisited, Visited};
+use common::Error;
+use grid::{Direction, Grid, Position};
 use std::fmt::{Display, Formatter};
 
-#[derive(Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Debug, Hash)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    pub fn left(&self) -> Direction {
-        match self {
-            Self::North => Self::West,
-            Self::East => Self::North,
-            Self::South => Self::East,
-            Self::West => Self::South,
-        }
-    }
-
-    pub fn right(&self) -> Direction {
-        match self {
-            Self::North => Self::East,
-            Self::East => Self::South,
-            Self::South => Self::West,
-            Self::West => Self::North,
-        }
-    }
-}
-
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
-pub struct Position {
-    row: usize,
-    column: usize,
+pub struct Beam {
+    position: Position,
     facing: Direction,
 }
 
-impl Position {
-    pub fn turn_left(&self) -> Self {
-        Position {
-            row: self.row,
-            column: self.column,
-            facing: self.facing.left(),
-        }
-    }
-
-    pub fn turn_right(&self) -> Self {
-        Position {
-            row: self.row,
-            column: self.column,
-            facing: self.facing.right(),
-        }
+impl Beam {
+    pub fn new(position: Position, facing: Direction) -> Self {
+        Self { position, facing }
     }
 
-    pub fn move_forwards(&self) -> Option<Self> {
-        match self.facing {
-            Direction::North => self.north(),
-            Direction::South => self.south(),
-            Direction::East => self.east(),
-            Direction::West => self.west(),
-        }
-    }
-
-    fn north(&self) -> Option<Self> {
-        if self.row > 0 {
-            Some(Position {
-                row: self.row - 1,
-                column: self.column,
-                facing: self.facing,
-            })
-        } else {
-            None
-        }
-    }
-
-    fn south(&self) -> Option<Self> {
-        Some(Position {
-            row: self.row + 1,
-            column: self.column,
-            facing: self.facing,
-        })
-    }
-
-    fn east(&self) -> Option<Self> {
-        Some(Position {
-            row: self.row,
-            column: self.column + 1,
-            facing: self.facing,
-        })
-    }
-
-    fn west(&self) -> Option<Self> {
-        if self.column > 0 {
-            Some(Position {
-                row: self.row,
-                column: self.column - 1,
-                facing: self.facing,
-            })
-        } else {
-            None
-        }
+    fn turn_left(&self) -> Self {
+        Self::new(self.position, self.facing.turn_left())
     }
-}
-
-trait Map<T> {
-    fn get_tile(&self, position: &Position) -> Option<&T>;
-}
 
-impl<T> Map<T> for Vec<Vec<T>> {
-    fn get_tile(&self, position: &Position) -> Option<&T> {
-        self.get(position.row)
-            .map(|row| row.get(position.column))
-            .flatten()
+    fn turn_right(&self) -> Self {
+        Self::new(self.position, self.facing.turn_right())
     }
 }
 
 pub struct Contraption {
-    schematic: Vec<Vec<Tile>>,
-    seen_beams: HashSet<Position>,
+    schematic: Grid<Tile>,
 }
 
 impl Contraption {
-    pub fn new(input: &str) -> Self {
-        let schematic = input
-            .lines()
-            .map(|row| row.chars().map(Tile::new).collect())
-            .collect();
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let schematic = Grid::parse(input, Tile::new)?;
 
-        let beams = HashSet::new();
+        Ok(Self { schematic })
+    }
 
-        Self {
-            schematic,
-            seen_beams: beams,
-        }
+    fn move_forwards(&self, beam: Beam) -> Option<Beam> {
+        self.schematic
+            .step(beam.position, beam.facing)
+            .map(|position| Beam::new(position, beam.facing))
     }
 
-    fn track_beam(&mut self, position: Option<Position>) {
-        let position = match position {
-            Some(p) => p,
-            None => {
-                return;
-            }
-        };
+    // a beam leaves its tile one way, or two for a splitter it meets side on
+    fn next_beams(&self, beam: &Beam) -> impl Iterator<Item = Beam> + '_ {
+        let vertical = matches!(beam.facing, Direction::North | Direction::South);
 
-        let tile = match self.schematic.get_tile(&position) {
-            Some(t) => t,
-            None => {
-                return;
+        let turns = match (&self.schematic[beam.position], vertical) {
+            (Tile::Empty, _)
+            | (Tile::Splitter(Orientation::Vertical), true)
+            | (Tile::Splitter(Orientation::Horizontal), false) => [Some(*beam), None],
+            (Tile::Mirror(Tilt::Right), true) | (Tile::Mirror(Tilt::Left), false) => {
+                [Some(beam.turn_right()), None]
+            }
+            (Tile::Mirror(Tilt::Right), false) | (Tile::Mirror(Tilt::Left), true) => {
+                [Some(beam.turn_left()), None]
             }
+            (Tile::Splitter(_), _) => [Some(beam.turn_left()), Some(beam.turn_right())],
         };
 
-        if self.seen_beams.contains(&position) {
-            return;
-        }
-
-        self.seen_beams.insert(position);
-
-        match tile {
-            Tile::Empty => self.track_beam(position.move_forwards()),
-            Tile::Mirror(Tilt::Right) => match position.facing {
-                Direction::North | Direction::South => {
-                    self.track_beam(position.turn_right().move_forwards())
-                }
-                Direction::East | Direction::West => {
-                    self.track_beam(position.turn_left().move_forwards())
-                }
-            },
-            Tile::Mirror(Tilt::Left) => match position.facing {
-                Direction::North | Direction::South => {
-                    self.track_beam(position.turn_left().move_forwards())
-                }
-                Direction::East | Direction::West => {
-                    self.track_beam(position.turn_right().move_forwards())
-                }
-            },
-            Tile::Splitter(Orientation::Vertical) => match position.facing {
-                Direction::North | Direction::South => self.track_beam(position.move_forwards()),
-                Direction::East | Direction::West => {
-                    self.track_beam(position.turn_left().move_forwards());
-                    self.track_beam(position.turn_right().move_forwards());
-                }
-            },
-            Tile::Splitter(Orientation::Horizontal) => match position.facing {
-                Direction::North | Direction::South => {
-                    self.track_beam(position.turn_left().move_forwards());
-                    self.track_beam(position.turn_right().move_forwards());
-                }
-                Direction::East | Direction::West => self.track_beam(position.move_forwards()),
-            },
-        }
+        turns
+            .into_iter()
+            .flatten()
+            .filter_map(|beam| self.move_forwards(beam))
     }
 
-    fn count_energised(&self) -> usize {
-        let energised_tiles: HashSet<(usize, usize)> =
-            self.seen_beams.iter().map(|p| (p.row, p.column)).collect();
-
-        energised_tiles.len()
+    // beams number by cell, then facing
+    fn index(&self, beam: &Beam) -> usize {
+        (beam.position.row * self.schematic.columns() + beam.position.column) * 4
+            + beam.facing as usize
     }
 
-    pub fn calculate_energy_level(&mut self, position: Position) -> usize {
-        self.seen_beams = HashSet::new();
+    pub fn calculate_energy_level(&self, beam: Beam) -> Result<usize, Error> {
+        let mut seen_beams = DenseVisited::new(self.schematic.cells().len() * 4, |beam: &Beam| {
+            self.index(beam)
+        });
 
-        self.track_beam(Some(position));
+        let start = self.schematic.contains(beam.position).then_some(beam);
+        search::dfs(
+            start,
+            |beam| self.next_beams(beam),
+            |_| false,
+            &mut seen_beams,
+        )?;
+
+        let energised = self
+            .schematic
+            .positions()
+            .filter(|&position| {
+                Direction::ALL
+                    .into_iter()
+                    .any(|facing| seen_beams.contains(&Beam::new(position, facing)))
+            })
+            .count();
 
-        self.count_energised()
+        Ok(energised)
     }
 
-    pub fn calculate_max_energy(&mut self) -> usize {
-        let number_of_columns = self.schematic.first().unwrap().len();
+    // the beam enters the top-left corner heading east
+    pub fn calculate_initial_energy(&self) -> Result<usize, Error> {
+        self.calculate_energy_level(Beam::new(Position::new(0, 0), Direction::East))
+    }
 
-        let mut starting_positions: Vec<Position> =
-            Vec::with_capacity(number_of_columns * self.schematic.len());
+    pub fn calculate_max_energy(&self) -> Result<usize, Error> {
+        let last_row = self.schematic.rows().saturating_sub(1);
+        let last_column = self.schematic.columns().saturating_sub(1);
 
-        starting_positions.extend(self.schematic.first().unwrap().iter().enumerate().map(
-            |(column, ..)| Position {
-                row: 0,
-                column,
-                facing: Direction::South,
-            },
-        ));
+        let mut starting_beams: Vec<Beam> =
+            Vec::with_capacity(2 * (self.schematic.rows() + self.schematic.columns()));
 
-        starting_positions.extend(self.schematic.last().unwrap().iter().enumerate().map(
-            |(column, ..)| Position {
-                row: self.schematic.len() - 1,
-                column,
-                facing: Direction::North,
-            },
-        ));
+        for column in 0..self.schematic.columns() {
+            starting_beams.push(Beam::new(Position::new(0, column), Direction::South));
+            starting_beams.push(Beam::new(Position::new(last_row, column), Direction::North));
+        }
 
-        starting_positions.extend(self.schematic.iter().enumerate().map(|(row, ..)| Position {
-            row,
-            column: 0,
-            facing: Direction::East,
-        }));
+        for row in 0..self.schematic.rows() {
+            starting_beams.push(Beam::new(Position::new(row, 0), Direction::East));
+            starting_beams.push(Beam::new(Position::new(row, last_column), Direction::West));
+        }
 
-        starting_positions.extend(self.schematic.iter().enumerate().map(|(row, ..)| Position {
-            row,
-            column: number_of_columns - 1,
-            facing: Direction::West,
-        }));
+        let energies = starting_beams
+            .into_iter()
+            .map(|beam| {
+                let energy = self.calculate_energy_level(beam)?;
+                tracing::debug!(
+                    row = beam.position.row,
+                    column = beam.position.column,
+                    facing = ?beam.facing,
+                    energy,
+                    "entry point"
+                );
+                Ok(energy)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
 
-        starting_positions
-            .iter()
-            .map(|&p| self.calculate_energy_level(p))
-            .max()
-            .unwrap()
+        Ok(energies.into_iter().max().unwrap_or(0))
     }
 }
 
 impl Display for Contraption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let lines: Vec<String> = self
-            .schematic
-            .iter()
-            .map(|row| String::from_iter(row.iter().map(|tile| tile.to_char())))
-            .collect();
-
-        write!(f, "{}", lines.join("\n"))
+        write!(f, "{}", self.schematic)
     }
 }
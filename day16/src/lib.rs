@@ -0,0 +1,26 @@
+pub mod contraption;
+pub mod tile;
+
+use crate::contraption::Contraption;
+use common::{Error, Solution};
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Contraption;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Contraption::new(input)
+    }
+
+    fn part1(contraption: &Self::Parsed) -> Result<Self::Answer, Error> {
+        contraption.calculate_initial_energy()
+    }
+
+    fn part2(contraption: &Self::Parsed) -> Result<Self::Answer, Error> {
+        contraption.calculate_max_energy()
+    }
+}
@@ -1,15 +1,6 @@
-mod contraption;
-mod tile;
+use day16::Day16;
+use std::process::ExitCode;
 
-use crate::contraption::Contraption;
-use std::fs::read_to_string;
-
-fn main() {
-    let input = read_to_string("input.txt").unwrap();
-
-    let mut contraption = Contraption::new(&input);
-
-    let result = contraption.calculate_max_energy();
-
-    println!("{result}")
+fn main() -> ExitCode {
+    common::run::<Day16>()
 }
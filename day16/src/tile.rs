@@ -1,3 +1,4 @@
+use common::Error;
 use std::fmt::{Display, Formatter, Write};
 
 pub enum Tilt {
@@ -17,14 +18,14 @@ pub enum Tile {
 }
 
 impl Tile {
-    pub fn new(c: char) -> Self {
+    pub fn new(c: char) -> Result<Self, Error> {
         match c {
-            '.' => Self::Empty,
-            '\\' => Self::Mirror(Tilt::Left),
-            '/' => Self::Mirror(Tilt::Right),
-            '|' => Self::Splitter(Orientation::Vertical),
-            '-' => Self::Splitter(Orientation::Horizontal),
-            _ => panic!("Unexpected input"),
+            '.' => Ok(Self::Empty),
+            '\\' => Ok(Self::Mirror(Tilt::Left)),
+            '/' => Ok(Self::Mirror(Tilt::Right)),
+            '|' => Ok(Self::Splitter(Orientation::Vertical)),
+            '-' => Ok(Self::Splitter(Orientation::Horizontal)),
+            _ => Err(Error::parse(format!("Unknown tile '{c}'"))),
         }
     }
 
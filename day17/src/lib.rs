@@ -0,0 +1,171 @@
+use common::search::{self, DenseVisited};
+use common::{Error, Solution};
+use grid::{Direction, Grid, Position};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Crucible {
+    pub min_run: u8,
+    pub max_run: u8,
+}
+
+pub const CRUCIBLE: Crucible = Crucible {
+    min_run: 1,
+    max_run: 3,
+};
+
+pub const ULTRA_CRUCIBLE: Crucible = Crucible {
+    min_run: 4,
+    max_run: 10,
+};
+
+impl Crucible {
+    fn can_turn(&self, remaining: u8) -> bool {
+        remaining <= self.max_run - self.min_run
+    }
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub struct State {
+    position: Position,
+    facing: Direction,
+    remaining: u8,
+}
+
+impl State {
+    pub fn turn_left(&self, crucible: &Crucible) -> Option<Self> {
+        self.turn(crucible, self.facing.turn_left())
+    }
+
+    pub fn turn_right(&self, crucible: &Crucible) -> Option<Self> {
+        self.turn(crucible, self.facing.turn_right())
+    }
+
+    fn turn(&self, crucible: &Crucible, facing: Direction) -> Option<Self> {
+        crucible.can_turn(self.remaining).then_some(State {
+            position: self.position,
+            facing,
+            remaining: crucible.max_run,
+        })
+    }
+
+    pub fn move_forwards(&self, map: &Grid<u32>) -> Option<Self> {
+        if self.remaining < 1 {
+            return None;
+        }
+
+        Some(State {
+            position: map.step(self.position, self.facing)?,
+            facing: self.facing,
+            remaining: self.remaining - 1,
+        })
+    }
+}
+
+pub struct Explorer<'a> {
+    map: &'a Grid<u32>,
+    crucible: Crucible,
+    target: Position,
+    // what the cheapest block costs to enter, 0 if any block is free
+    cheapest: u32,
+}
+
+impl<'a> Explorer<'a> {
+    pub fn new(map: &'a Grid<u32>, crucible: Crucible) -> Self {
+        Self {
+            map,
+            crucible,
+            target: Position::new(
+                map.rows().saturating_sub(1),
+                map.columns().saturating_sub(1),
+            ),
+            cheapest: map.cells().iter().copied().min().unwrap_or_default(),
+        }
+    }
+
+    // states number by cell, then facing, then moves remaining
+    fn index(&self, state: &State) -> usize {
+        let cell = state.position.row * self.map.columns() + state.position.column;
+
+        (cell * 4 + state.facing as usize) * (self.crucible.max_run as usize + 1)
+            + state.remaining as usize
+    }
+
+    fn next_states(&self, state: &State) -> impl Iterator<Item = (State, u32)> + '_ {
+        [
+            state.move_forwards(self.map),
+            state
+                .turn_left(&self.crucible)
+                .and_then(|s| s.move_forwards(self.map)),
+            state
+                .turn_right(&self.crucible)
+                .and_then(|s| s.move_forwards(self.map)),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|next| (next, self.map[next.position]))
+    }
+
+    // every block left to enter costs at least the cheapest, so this never overestimates, and a
+    // move of one block lowers it by at most the cheapest, which is no more than the move cost,
+    // so it's consistent too. With free blocks it's 0, and the search is Dijkstra's.
+    fn heat_loss_left(&self, state: &State) -> u32 {
+        (self.target.row.abs_diff(state.position.row)
+            + self.target.column.abs_diff(state.position.column)) as u32
+            * self.cheapest
+    }
+
+    pub fn search(&self) -> Result<Option<u32>, Error> {
+        let starts = [Direction::East, Direction::South].map(|facing| State {
+            position: Position::new(0, 0),
+            facing,
+            remaining: self.crucible.max_run,
+        });
+
+        let size = self.map.cells().len() * 4 * (self.crucible.max_run as usize + 1);
+        let mut visited = DenseVisited::new(size, |state: &State| self.index(state));
+
+        let found = search::astar(
+            starts,
+            |state| self.next_states(state),
+            |state| self.heat_loss_left(state),
+            |state| state.position == self.target && self.crucible.can_turn(state.remaining),
+            &mut visited,
+        )?;
+
+        Ok(found.map(|(_, heat_loss)| heat_loss))
+    }
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Grid<u32>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let map = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::parse(format!("Expected a heat loss digit, found '{c}'")))
+        })?;
+
+        if map.is_empty() {
+            return Err(Error::parse("Expected a map"));
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let explorer = Explorer::new(map, CRUCIBLE);
+
+        explorer.search()?.ok_or(Error::NoSolution)
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let explorer = Explorer::new(map, ULTRA_CRUCIBLE);
+
+        explorer.search()?.ok_or(Error::NoSolution)
+    }
+}
@@ -0,0 +1,247 @@
+use ascii::{AsAsciiStr, AsciiChar};
+use common::{parse, Error, Solution};
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Up => f.write_char('U'),
+            Direction::Down => f.write_char('D'),
+            Direction::Left => f.write_char('L'),
+            Direction::Right => f.write_char('R'),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(Error::parse(format!("Unknown direction '{s}'"))),
+        }
+    }
+}
+
+impl TryFrom<AsciiChar> for Direction {
+    type Error = Error;
+
+    fn try_from(value: AsciiChar) -> Result<Self, Self::Error> {
+        match value {
+            AsciiChar::_0 => Ok(Direction::Right),
+            AsciiChar::_1 => Ok(Direction::Down),
+            AsciiChar::_2 => Ok(Direction::Left),
+            AsciiChar::_3 => Ok(Direction::Up),
+            _ => Err(Error::parse(format!("Unknown direction digit '{value}'"))),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub distance: i64,
+}
+
+// the plain "R 6" form, as the instructions are first read
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = s.split_whitespace();
+
+        let direction = sections
+            .next()
+            .ok_or_else(|| Error::parse("Expected a direction"))?
+            .parse()?;
+
+        let distance = parse::number(
+            sections
+                .next()
+                .ok_or_else(|| Error::parse("Expected a distance"))?,
+        )?;
+
+        if distance <= 0 {
+            return Err(Error::parse(format!(
+                "Expected a positive distance, found {distance}"
+            )));
+        }
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+// the real instruction, hidden in the hex colour code
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let colour = value
+            .split_whitespace()
+            .last()
+            .ok_or_else(|| Error::parse("Expected a colour code"))?;
+
+        let hex_str = colour
+            .strip_prefix("(#")
+            .and_then(|colour| colour.strip_suffix(')'))
+            .filter(|hex_str| hex_str.len() == 6)
+            .and_then(|hex_str| hex_str.as_ascii_str().ok())
+            .ok_or_else(|| {
+                Error::parse(format!(
+                    "Expected a colour like '(#70c710)', found '{colour}'"
+                ))
+            })?;
+
+        let direction = hex_str[5].try_into()?;
+
+        let distance = i64::from_str_radix(hex_str[..5].as_str(), 16).map_err(|_| {
+            Error::parse(format!(
+                "Expected a hex distance, found '{}'",
+                &hex_str[..5]
+            ))
+        })?;
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.direction, self.distance)
+    }
+}
+
+pub type Coordinate = (i64, i64);
+
+// None when the move goes past what fits in an i64
+trait Euclidean: Sized {
+    fn up(&self, distance: i64) -> Option<Self>;
+    fn down(&self, distance: i64) -> Option<Self>;
+    fn right(&self, distance: i64) -> Option<Self>;
+    fn left(&self, distance: i64) -> Option<Self>;
+}
+
+impl Euclidean for Coordinate {
+    fn up(&self, distance: i64) -> Option<Self> {
+        Some((self.0, self.1.checked_add(distance)?))
+    }
+
+    fn down(&self, distance: i64) -> Option<Self> {
+        Some((self.0, self.1.checked_sub(distance)?))
+    }
+
+    fn right(&self, distance: i64) -> Option<Self> {
+        Some((self.0.checked_add(distance)?, self.1))
+    }
+
+    fn left(&self, distance: i64) -> Option<Self> {
+        Some((self.0.checked_sub(distance)?, self.1))
+    }
+}
+
+fn too_big() -> Error {
+    Error::invalid_state("The lagoon is bigger than fits in an i64")
+}
+
+pub fn build_vertices(instructions: &[Instruction]) -> Result<Vec<Coordinate>, Error> {
+    let mut coordinates: Vec<Coordinate> = Vec::with_capacity(instructions.len() + 1);
+
+    let mut coordinate: Coordinate = (0, 0);
+
+    for instruction in instructions {
+        coordinate = match instruction.direction {
+            Direction::Up => coordinate.up(instruction.distance),
+            Direction::Down => coordinate.down(instruction.distance),
+            Direction::Left => coordinate.left(instruction.distance),
+            Direction::Right => coordinate.right(instruction.distance),
+        }
+        .ok_or_else(too_big)?;
+
+        coordinates.push(coordinate);
+    }
+
+    Ok(coordinates)
+}
+
+pub fn calculate_area(vertices: &[Coordinate]) -> Result<i64, Error> {
+    // shoelace formula -- https://en.wikipedia.org/wiki/Shoelace_formula
+    let twice_area = vertices
+        .windows(2)
+        .try_fold(0_i64, |total, edge| {
+            let term = edge[0]
+                .0
+                .checked_mul(edge[1].1)?
+                .checked_sub(edge[0].1.checked_mul(edge[1].0)?)?;
+            total.checked_add(term)
+        })
+        .ok_or_else(too_big)?;
+
+    Ok(twice_area / 2)
+}
+
+pub fn calculate_lagoon_size(instructions: &[Instruction]) -> Result<i64, Error> {
+    let vertices = build_vertices(instructions)?;
+
+    let area = calculate_area(&vertices)?
+        .checked_abs()
+        .ok_or_else(too_big)?;
+    let border = instructions
+        .iter()
+        .try_fold(0_i64, |total, i| total.checked_add(i.distance))
+        .ok_or_else(too_big)?;
+
+    // pick's theorem, rearranged to find (b+i) -- https://en.wikipedia.org/wiki/Pick's_theorem
+    area.checked_add(border / 2 + 1).ok_or_else(too_big)
+}
+
+pub struct DigPlan {
+    pub instructions: Vec<Instruction>,
+    pub hex_instructions: Vec<Instruction>,
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = DigPlan;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let instructions = parse::lines(input, Instruction::from_str)?;
+
+        let hex_instructions = parse::lines(input, Instruction::try_from)?;
+
+        Ok(DigPlan {
+            instructions,
+            hex_instructions,
+        })
+    }
+
+    fn part1(plan: &Self::Parsed) -> Result<Self::Answer, Error> {
+        calculate_lagoon_size(&plan.instructions)
+    }
+
+    fn part2(plan: &Self::Parsed) -> Result<Self::Answer, Error> {
+        calculate_lagoon_size(&plan.hex_instructions)
+    }
+}
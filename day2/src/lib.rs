@@ -0,0 +1,139 @@
+use common::{parse, Error, Solution};
+use std::cmp::max;
+
+pub struct Sample {
+    pub red: u64,
+    pub green: u64,
+    pub blue: u64,
+}
+
+impl Sample {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut red: u64 = 0;
+        let mut green: u64 = 0;
+        let mut blue: u64 = 0;
+
+        let counts: Vec<&str> = input.split(',').map(|s| s.trim()).collect();
+
+        for count in counts {
+            let (count, colour) = count.split_once(' ').ok_or_else(|| {
+                Error::parse(format!("Expected a count and a colour, found '{count}'"))
+            })?;
+
+            let count: u64 = parse::number(count)?;
+
+            let total = match colour.trim() {
+                "red" => &mut red,
+                "green" => &mut green,
+                "blue" => &mut blue,
+                _ => return Err(Error::parse(format!("Unknown colour '{colour}'"))),
+            };
+
+            *total = total.checked_add(count).ok_or_else(|| {
+                Error::parse(format!(
+                    "The {colour} cubes add up to more than fit in a u64"
+                ))
+            })?;
+        }
+
+        Ok(Self { red, green, blue })
+    }
+
+    pub fn total_seen(samples: &[Self]) -> Self {
+        let mut max_red = 0;
+        let mut max_green = 0;
+        let mut max_blue = 0;
+
+        for &Self { red, green, blue } in samples {
+            max_red = max(red, max_red);
+            max_green = max(green, max_green);
+            max_blue = max(blue, max_blue);
+        }
+
+        Self {
+            red: max_red,
+            green: max_green,
+            blue: max_blue,
+        }
+    }
+}
+
+pub struct Game {
+    pub id: u64,
+    pub samples: Vec<Sample>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let (name, samples) = input
+            .split_once(':')
+            .ok_or_else(|| Error::parse("Expected 'Game <id>:'"))?;
+
+        let id = name
+            .strip_prefix("Game ")
+            .ok_or_else(|| Error::parse("Expected 'Game <id>:'"))
+            .and_then(parse::number)?;
+
+        let samples: Vec<Sample> = samples
+            .split(';')
+            .map(|s| s.trim())
+            .map(Sample::parse)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { id, samples })
+    }
+
+    pub fn is_possible(&self, bag: &Sample) -> bool {
+        let Sample { red, green, blue } = Sample::total_seen(&self.samples);
+
+        red <= bag.red && green <= bag.green && blue <= bag.blue
+    }
+
+    pub fn calculate_power(&self) -> Result<u64, Error> {
+        let Sample { red, green, blue } = Sample::total_seen(&self.samples);
+
+        red.checked_mul(green)
+            .and_then(|power| power.checked_mul(blue))
+            .ok_or_else(|| {
+                Error::invalid_state(format!("Game {}'s power doesn't fit in a u64", self.id))
+            })
+    }
+}
+
+fn sum(mut values: impl Iterator<Item = Result<u64, Error>>) -> Result<u64, Error> {
+    values.try_fold(0_u64, |total, value| {
+        total
+            .checked_add(value?)
+            .ok_or_else(|| Error::invalid_state("The sum doesn't fit in a u64"))
+    })
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Game>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse::lines(input, Game::parse)
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let bag = Sample {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        sum(games
+            .iter()
+            .filter(|game| game.is_possible(&bag))
+            .map(|game| Ok(game.id)))
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<Self::Answer, Error> {
+        sum(games.iter().map(Game::calculate_power))
+    }
+}
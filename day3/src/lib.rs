@@ -0,0 +1,138 @@
+use common::{Error, Solution};
+use grid::{Grid, Position};
+
+#[derive(Debug)]
+pub struct Number {
+    pub value: u64,
+    pub location: Position, // left-most digit
+    pub length: usize,
+}
+
+impl Number {
+    // every position touching the number, diagonals included, plus the number itself
+    fn surroundings(&self) -> impl Iterator<Item = Position> + '_ {
+        let left = self.location.column.saturating_sub(1);
+        let right = self.location.column + self.length;
+        let top = self.location.row.saturating_sub(1);
+        let bottom = self.location.row + 1;
+
+        (top..=bottom)
+            .flat_map(move |row| (left..=right).map(move |column| Position::new(row, column)))
+    }
+
+    pub fn is_part_number(&self, cells: &Grid<char>) -> bool {
+        self.surroundings()
+            .any(|position| cells.get(position).is_some_and(|&c| is_symbol(c)))
+    }
+
+    pub fn add_to_nearby_gears(&self, gears: &mut Grid<Option<Vec<u64>>>) {
+        for position in self.surroundings() {
+            if let Some(Some(gear)) = gears.get_mut(position) {
+                gear.push(self.value);
+            }
+        }
+    }
+}
+
+fn is_gear(c: char) -> bool {
+    c == '*'
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+fn build_gear_arrays(cells: &Grid<char>) -> Grid<Option<Vec<u64>>> {
+    cells.map(|&c| is_gear(c).then(Vec::new))
+}
+
+pub fn build_number_array(cells: &Grid<char>) -> Result<Vec<Number>, Error> {
+    let mut numbers = Vec::new();
+
+    for (row, line) in cells.iter_rows().enumerate() {
+        let mut column = 0;
+
+        while column < line.len() {
+            let length = line[column..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+
+            if length == 0 {
+                column += 1;
+                continue;
+            }
+
+            let digits: String = line[column..column + length].iter().collect();
+
+            let value = digits.parse().map_err(|_| {
+                Error::parse(format!("Number {digits} is out of range")).at(row + 1, column + 1)
+            })?;
+
+            numbers.push(Number {
+                value,
+                location: Position::new(row, column),
+                length,
+            });
+
+            column += length;
+        }
+    }
+
+    Ok(numbers)
+}
+
+pub struct Schematic {
+    pub cells: Grid<char>,
+    pub numbers: Vec<Number>,
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Schematic;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let cells = Grid::parse(input, Ok)?;
+
+        let numbers = build_number_array(&cells)?;
+
+        Ok(Schematic { cells, numbers })
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Result<Self::Answer, Error> {
+        schematic
+            .numbers
+            .iter()
+            .filter(|number| number.is_part_number(&schematic.cells))
+            .try_fold(0_u64, |total, number| total.checked_add(number.value))
+            .ok_or_else(|| Error::invalid_state("The sum of the part numbers doesn't fit in a u64"))
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let mut gears = build_gear_arrays(&schematic.cells);
+
+        for number in &schematic.numbers {
+            number.add_to_nearby_gears(&mut gears);
+        }
+
+        let mut total: u64 = 0;
+
+        for gear in gears.cells().iter().flatten() {
+            if gear.len() > 1 {
+                total = gear
+                    .iter()
+                    .try_fold(1_u64, |product, &number| product.checked_mul(number))
+                    .and_then(|ratio| total.checked_add(ratio))
+                    .ok_or_else(|| {
+                        Error::invalid_state("The sum of the gear ratios doesn't fit in a u64")
+                    })?;
+            }
+        }
+
+        Ok(total)
+    }
+}
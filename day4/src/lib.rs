@@ -0,0 +1,102 @@
+use common::{parse, Error, Solution};
+use std::cmp::min;
+
+#[derive(Eq, PartialEq, Hash)]
+pub struct Game {
+    pub numbers: Vec<u64>,
+    pub winning: Vec<u64>,
+}
+
+pub fn calculate_score(game: &Game) -> u64 {
+    let mut total = 0;
+
+    for number in &game.numbers {
+        if game.winning.contains(number) {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+pub fn read_game(input: &str) -> Result<Game, Error> {
+    let (_, after_colon) = input
+        .split_once(':')
+        .ok_or_else(|| Error::parse("Expected 'Card <id>:'"))?;
+    let (winning, numbers) = after_colon
+        .split_once('|')
+        .ok_or_else(|| Error::parse("Expected winning numbers and numbers separated by '|'"))?;
+
+    let winning: Vec<u64> = winning
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+
+    let numbers = numbers
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { numbers, winning })
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<Game>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse::lines(input, read_game)
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<Self::Answer, Error> {
+        games
+            .iter()
+            .map(calculate_score)
+            .filter(|&score| score > 0)
+            .try_fold(0_u64, |total, score| {
+                u32::try_from(score - 1)
+                    .ok()
+                    .and_then(|shift| 1_u64.checked_shl(shift))
+                    .and_then(|points| total.checked_add(points))
+            })
+            .ok_or_else(|| Error::invalid_state("The points don't fit in a u64"))
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let num_matching_records: Vec<u64> = games.iter().map(calculate_score).collect();
+        let mut card_counts: Vec<u64> = games.iter().map(|_| 1).collect();
+
+        for i in 0..num_matching_records.len() {
+            let num_of_this_card = card_counts[i];
+            let num_matching = num_matching_records[i];
+
+            tracing::debug!(
+                card = i + 1,
+                copies = num_of_this_card,
+                "each copy wins a copy of the next {num_matching} cards"
+            );
+
+            for j in
+                min(card_counts.len(), i + 1)..min(card_counts.len(), 1 + i + num_matching as usize)
+            {
+                card_counts[j] = card_counts[j]
+                    .checked_add(num_of_this_card)
+                    .ok_or_else(|| {
+                        Error::invalid_state(format!(
+                            "Card {} has more copies than fit in a u64",
+                            j + 1
+                        ))
+                    })?;
+            }
+        }
+
+        card_counts
+            .iter()
+            .try_fold(0_u64, |total, &count| total.checked_add(count))
+            .ok_or_else(|| Error::invalid_state("The number of cards doesn't fit in a u64"))
+    }
+}
@@ -0,0 +1,245 @@
+use common::repl::{self, Command, Repl};
+use common::{cancel, parse, Error, Solution};
+use rayon::prelude::*;
+
+pub struct MapEntry {
+    pub source_start: i64,
+    pub destination_start: i64,
+    pub length: i64,
+}
+
+impl MapEntry {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let numbers: Vec<i64> = input
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+
+        let [destination_start, source_start, length] = numbers[..] else {
+            return Err(Error::parse(format!(
+                "Expected 3 numbers in a map entry, found {}",
+                numbers.len()
+            )));
+        };
+
+        // so mapping any source in the range can't overflow
+        if source_start.checked_add(length).is_none()
+            || destination_start.checked_add(length).is_none()
+            || destination_start.checked_sub(source_start).is_none()
+        {
+            return Err(Error::parse(
+                "Expected a map entry whose ranges fit in an i64",
+            ));
+        }
+
+        Ok(Self {
+            destination_start,
+            source_start,
+            length,
+        })
+    }
+
+    pub fn calculate_destination(&self, source: i64) -> Option<i64> {
+        let source_max = self.source_start + self.length - 1;
+
+        if source < self.source_start || source > source_max {
+            return None;
+        }
+
+        let difference = self.destination_start - self.source_start;
+
+        Some(source + difference)
+    }
+}
+
+pub struct Mapperer {
+    // from the header, e.g. "seed-to-soil"
+    pub name: String,
+    pub map_entries: Vec<MapEntry>,
+}
+
+impl Mapperer {
+    // first_line is the line number of the map's header, for error reporting
+    pub fn new(input: &str, first_line: usize) -> Result<Self, Error> {
+        let header = input.lines().next().unwrap_or_default();
+        let name = header.trim_end_matches(" map:").to_string();
+
+        let map_entries =
+            parse::numbered_lines(input.lines().skip(1), first_line + 1, MapEntry::new)?;
+
+        Ok(Self { name, map_entries })
+    }
+
+    // what the map's destinations are, e.g. "soil"
+    pub fn destination(&self) -> &str {
+        self.name.rsplit("-to-").next().unwrap_or_default()
+    }
+
+    pub fn calculate_destination(&self, source: i64) -> i64 {
+        self.map_entries
+            .iter()
+            .filter_map(|m| m.calculate_destination(source))
+            .next()
+            .unwrap_or(source)
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub mapperers: Vec<Mapperer>,
+}
+
+impl Almanac {
+    pub fn calculate_location(&self, seed: i64) -> i64 {
+        self.mapperers
+            .iter()
+            .enumerate()
+            .fold(seed, |source, (i, m)| {
+                let destination = m.calculate_destination(source);
+                tracing::trace!(seed, map = i + 1, source, destination);
+                destination
+            })
+    }
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Almanac;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut input_sections = parse::split_paragraphs(input);
+
+        let (seeds_line, seeds) = input_sections
+            .next()
+            .and_then(|(line, section)| Some((line, section.strip_prefix("seeds:")?)))
+            .ok_or_else(|| Error::parse("Expected 'seeds:'").at_line(1))?;
+
+        let seeds: Vec<i64> = seeds
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, Error>>()
+            .map_err(|e| e.at_line(seeds_line))?;
+
+        let mapperers = input_sections
+            .map(|(first_line, section)| Mapperer::new(section, first_line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac { seeds, mapperers })
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Result<Self::Answer, Error> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| almanac.calculate_location(seed))
+            .min()
+            .ok_or(Error::NoSolution)
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Result<Self::Answer, Error> {
+        // the thread pool's threads don't share ours, so they're handed the token to check
+        let token = cancel::current();
+
+        let ranges = almanac
+            .seeds
+            .chunks_exact(2)
+            .map(|chunk| {
+                let end = chunk[0].checked_add(chunk[1]).ok_or_else(|| {
+                    Error::invalid_state(format!(
+                        "The seed range starting at {} ends past what fits in an i64",
+                        chunk[0]
+                    ))
+                })?;
+                Ok(chunk[0]..end)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let location = ranges
+            .into_par_iter()
+            .flat_map(|range| range)
+            .take_any_while(|_| !token.is_cancelled())
+            .map(|n| almanac.calculate_location(n))
+            .min();
+
+        token.checkpoint()?;
+        location.ok_or(Error::NoSolution)
+    }
+}
+
+impl Repl for Day5 {
+    type State = Almanac;
+
+    fn start(almanac: Self::Parsed) -> Result<Self::State, Error> {
+        Ok(almanac)
+    }
+
+    fn commands() -> Vec<Command<Self::State>> {
+        vec![
+            Command {
+                name: "seeds",
+                arguments: "",
+                help: "List the seeds",
+                run: |almanac, _| {
+                    let seeds: Vec<String> = almanac.seeds.iter().map(i64::to_string).collect();
+                    Ok(seeds.join(" "))
+                },
+            },
+            Command {
+                name: "maps",
+                arguments: "",
+                help: "List the maps in the order they're applied",
+                run: |almanac, _| {
+                    let maps: Vec<String> = almanac
+                        .mapperers
+                        .iter()
+                        .enumerate()
+                        .map(|(i, m)| {
+                            format!("{} {} ({} entries)", i + 1, m.name, m.map_entries.len())
+                        })
+                        .collect();
+                    Ok(maps.join("\n"))
+                },
+            },
+            Command {
+                name: "entries",
+                arguments: "MAP",
+                help: "Show a map's entries as destination, source and length",
+                run: |almanac, arguments| {
+                    let index: usize = repl::argument(arguments, 0, "a map number")?;
+                    let mapperer = index
+                        .checked_sub(1)
+                        .and_then(|i| almanac.mapperers.get(i))
+                        .ok_or_else(|| format!("There is no map {index}"))?;
+
+                    let entries: Vec<String> = mapperer
+                        .map_entries
+                        .iter()
+                        .map(|e| format!("{} {} {}", e.destination_start, e.source_start, e.length))
+                        .collect();
+                    Ok(format!("{}\n{}", mapperer.name, entries.join("\n")))
+                },
+            },
+            Command {
+                name: "map",
+                arguments: "SEED",
+                help: "Follow a seed through every map to its location",
+                run: |almanac, arguments| {
+                    let seed: i64 = repl::argument(arguments, 0, "a seed")?;
+
+                    let mut source = seed;
+                    let mut hops = vec![format!("seed {seed}")];
+                    for mapperer in &almanac.mapperers {
+                        source = mapperer.calculate_destination(source);
+                        hops.push(format!("{} {source}", mapperer.destination()));
+                    }
+
+                    Ok(hops.join(" -> "))
+                },
+            },
+        ]
+    }
+}
@@ -0,0 +1,106 @@
+use common::{parse, Error, Solution};
+
+pub struct Race {
+    pub time: i64,
+    pub distance: i64,
+}
+
+impl Race {
+    pub fn new((time, distance): (i64, i64)) -> Self {
+        Self { time, distance }
+    }
+
+    pub fn ways_to_beat(&self) -> Result<i64, Error> {
+        // solvable with the quadratic equation instead of search, in i128 so squaring the time
+        // can't overflow
+        let time = self.time as i128;
+        let discriminant = time * time - 4 * self.distance as i128;
+        if discriminant < 0 {
+            return Err(Error::invalid_state(format!(
+                "No press beats {} in a race of {}",
+                self.distance, self.time
+            )));
+        }
+
+        let sqrt = (discriminant as f64).sqrt();
+
+        let short_press = (-self.time as f64 - sqrt) / 2.0;
+        let long_press = (-self.time as f64 + sqrt) / 2.0;
+
+        Ok((long_press.ceil() - short_press.floor()) as i64 - 1)
+    }
+}
+
+pub struct Sheet {
+    pub times: Vec<i64>,
+    pub distances: Vec<i64>,
+}
+
+fn parse_row(line: &str, label: &str) -> Result<Vec<i64>, Error> {
+    line.strip_prefix(label)
+        .and_then(|line| line.strip_prefix(':'))
+        .ok_or_else(|| Error::parse(format!("Expected '{label}:'")))?
+        .split_whitespace()
+        .map(parse::number)
+        .collect()
+}
+
+// the numbers on each row are really one number with bad kerning
+fn join_row(numbers: &[i64]) -> Result<i64, Error> {
+    let joined: String = numbers.iter().map(|n| n.to_string()).collect();
+
+    joined
+        .parse()
+        .map_err(|_| Error::invalid_state(format!("Joined number {joined} is out of range")))
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Sheet;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut lines = input.lines();
+
+        let times =
+            parse_row(lines.next().unwrap_or_default(), "Time").map_err(|e| e.at_line(1))?;
+        let distances =
+            parse_row(lines.next().unwrap_or_default(), "Distance").map_err(|e| e.at_line(2))?;
+
+        if times.len() != distances.len() {
+            return Err(Error::parse(format!(
+                "Found {} times but {} distances",
+                times.len(),
+                distances.len()
+            )));
+        }
+
+        Ok(Sheet { times, distances })
+    }
+
+    fn part1(sheet: &Self::Parsed) -> Result<Self::Answer, Error> {
+        sheet
+            .times
+            .iter()
+            .copied()
+            .zip(sheet.distances.iter().copied())
+            .map(Race::new)
+            .try_fold(1_i64, |product, race| {
+                product
+                    .checked_mul(race.ways_to_beat()?)
+                    .ok_or_else(|| Error::invalid_state("The product doesn't fit in an i64"))
+            })
+    }
+
+    fn part2(sheet: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let time = join_row(&sheet.times)?;
+        let distance = join_row(&sheet.distances)?;
+
+        let race = Race::new((time, distance));
+
+        race.ways_to_beat()
+    }
+}
@@ -0,0 +1,270 @@
+use common::{parse, Error, Solution};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub enum Hand {
+    HighCard(Vec<Card>),
+    OnePair(Vec<Card>),
+    TwoPair(Vec<Card>),
+    ThreeOfAKind(Vec<Card>),
+    FullHouse(Vec<Card>),
+    FourOfAKind(Vec<Card>),
+    FiveOfAKind(Vec<Card>),
+}
+
+impl Hand {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::HighCard(_) => "high card",
+            Self::OnePair(_) => "one pair",
+            Self::TwoPair(_) => "two pair",
+            Self::ThreeOfAKind(_) => "three of a kind",
+            Self::FullHouse(_) => "full house",
+            Self::FourOfAKind(_) => "four of a kind",
+            Self::FiveOfAKind(_) => "five of a kind",
+        }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        match self {
+            Self::HighCard(cards)
+            | Self::OnePair(cards)
+            | Self::TwoPair(cards)
+            | Self::ThreeOfAKind(cards)
+            | Self::FullHouse(cards)
+            | Self::FourOfAKind(cards)
+            | Self::FiveOfAKind(cards) => cards,
+        }
+    }
+
+    // jacks become jokers: wild when classifying the hand, but the weakest card in a tie
+    pub fn with_jokers(&self) -> Result<Self, Error> {
+        let cards: Vec<Card> = self
+            .cards()
+            .iter()
+            .map(|&card| {
+                if card == Card::Jack {
+                    Card::Joker
+                } else {
+                    card
+                }
+            })
+            .collect();
+
+        Self::try_from(cards)
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.cards().iter().try_for_each(|card| write!(f, "{card}"))
+    }
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let cards: Vec<Card> = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Card::try_from(c).map_err(|e| e.at_column(i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Self::try_from(cards)
+    }
+}
+
+impl TryFrom<Vec<Card>> for Hand {
+    type Error = Error;
+
+    fn try_from(cards: Vec<Card>) -> Result<Self, Self::Error> {
+        if cards.len() != 5 {
+            return Err(Error::parse(format!(
+                "Expected 5 cards in a hand, found {}",
+                cards.len()
+            )));
+        }
+
+        let counter: HashMap<&Card, u64> =
+            cards
+                .iter()
+                .fold(HashMap::with_capacity(5), |mut counter, card| {
+                    counter.entry(card).and_modify(|n| *n += 1).or_insert(1);
+                    counter
+                });
+
+        let mut counts: Vec<(u64, &Card)> = counter
+            .iter()
+            .map(|(card, count)| (*count, *card))
+            .collect();
+
+        counts.sort();
+
+        let (mut highest_count, most_numerous_card) = counts
+            .pop()
+            .ok_or_else(|| Error::invalid_state("Hand has no cards"))?;
+
+        let mut second_highest = counts.pop().map(|(n, _)| n);
+
+        if *most_numerous_card == Card::Joker {
+            highest_count += second_highest.unwrap_or(0);
+            second_highest = counts.pop().map(|(n, _)| n)
+        } else {
+            let number_of_jokers = counter.get(&Card::Joker).unwrap_or(&0);
+            highest_count += number_of_jokers
+        }
+
+        let unclassifiable = || {
+            Error::invalid_state(format!(
+                "Can't classify a hand with {highest_count} and {second_highest:?} matching cards"
+            ))
+        };
+
+        let hand = match highest_count {
+            5 => Self::FiveOfAKind(cards),
+            4 => Self::FourOfAKind(cards),
+            3 => match second_highest {
+                Some(2) => Self::FullHouse(cards),
+                Some(1) => Self::ThreeOfAKind(cards),
+                _ => return Err(unclassifiable()),
+            },
+            2 => match second_highest {
+                Some(2) => Self::TwoPair(cards),
+                Some(1) => Self::OnePair(cards),
+                _ => return Err(unclassifiable()),
+            },
+            1 => Self::HighCard(cards),
+            _ => return Err(unclassifiable()),
+        };
+
+        tracing::debug!(%hand, kind = hand.kind(), highest_count, second_highest);
+
+        Ok(hand)
+    }
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'A' => Ok(Self::Ace),
+            'K' => Ok(Self::King),
+            'Q' => Ok(Self::Queen),
+            'T' => Ok(Self::Ten),
+            '9' => Ok(Self::Nine),
+            '8' => Ok(Self::Eight),
+            '7' => Ok(Self::Seven),
+            '6' => Ok(Self::Six),
+            '5' => Ok(Self::Five),
+            '4' => Ok(Self::Four),
+            '3' => Ok(Self::Three),
+            '2' => Ok(Self::Two),
+            'J' => Ok(Self::Jack),
+            _ => Err(Error::parse(format!("Unknown card '{value}'"))),
+        }
+    }
+}
+
+// jokers are written as the jacks they started out as
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Self::Ace => 'A',
+            Self::King => 'K',
+            Self::Queen => 'Q',
+            Self::Jack | Self::Joker => 'J',
+            Self::Ten => 'T',
+            Self::Nine => '9',
+            Self::Eight => '8',
+            Self::Seven => '7',
+            Self::Six => '6',
+            Self::Five => '5',
+            Self::Four => '4',
+            Self::Three => '3',
+            Self::Two => '2',
+        })
+    }
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub struct Play {
+    pub hand: Hand,
+    pub bid: u64,
+}
+
+impl TryFrom<&str> for Play {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (hand, bid) = value
+            .split_once(' ')
+            .ok_or_else(|| Error::parse("Expected a hand and a bid"))?;
+
+        let hand: Hand = Hand::try_from(hand)?;
+        let bid: u64 = parse::number(bid).map_err(|e| e.at_column(hand.cards().len() + 2))?;
+
+        Ok(Play { hand, bid })
+    }
+}
+
+fn total_winnings(mut plays: Vec<&Play>) -> u64 {
+    plays.sort();
+
+    plays
+        .iter()
+        .enumerate()
+        .map(|(i, Play { bid, .. })| (i + 1) as u64 * bid)
+        .sum()
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<Play>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse::lines(input, Play::try_from)
+    }
+
+    fn part1(plays: &Self::Parsed) -> Result<Self::Answer, Error> {
+        Ok(total_winnings(plays.iter().collect()))
+    }
+
+    fn part2(plays: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let plays: Vec<Play> = plays
+            .iter()
+            .map(|play| {
+                Ok(Play {
+                    hand: play.hand.with_jokers()?,
+                    bid: play.bid,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(total_winnings(plays.iter().collect()))
+    }
+}
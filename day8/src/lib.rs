@@ -0,0 +1,384 @@
+use common::cycle::{self, Cycle};
+use common::repl::{self, Command, Repl};
+use common::{cancel, parse, Error, Solution};
+use std::collections::HashMap;
+
+pub enum Direction {
+    Right,
+    Left,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'R' => Ok(Self::Right),
+            'L' => Ok(Self::Left),
+            _ => Err(Error::parse(format!("Unknown direction '{value}'"))),
+        }
+    }
+}
+
+pub struct Node {
+    pub name: String,
+    pub left: usize,
+    pub right: usize,
+}
+
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: Vec<Node>,
+    pub index: HashMap<String, usize>,
+}
+
+impl Network {
+    pub fn get_neighbour(&self, node: usize, direction: &Direction) -> usize {
+        match direction {
+            Direction::Right => self.nodes[node].right,
+            Direction::Left => self.nodes[node].left,
+        }
+    }
+
+    // None if the destination is never reached
+    pub fn count_steps(
+        &self,
+        starting_node: usize,
+        is_destination: impl Fn(&str) -> bool,
+    ) -> Option<u64> {
+        // after this many steps some (node, direction) state must have repeated
+        let limit = self.nodes.len() * self.directions.len();
+
+        let mut current_node = starting_node;
+        let steps = self
+            .directions
+            .iter()
+            .cycle()
+            .take(limit + 1)
+            .map_while(|direction| {
+                if is_destination(&self.nodes[current_node].name) {
+                    None
+                } else {
+                    current_node = self.get_neighbour(current_node, direction);
+                    Some(())
+                }
+            })
+            .count();
+
+        (steps <= limit).then_some(steps as u64)
+    }
+}
+
+fn parse_node_spec(input: &str) -> Result<(&str, &str, &str), Error> {
+    let invalid = || Error::parse(format!("Expected 'AAA = (BBB, CCC)', found '{input}'"));
+
+    let (name, neighbours) = input.split_once('=').ok_or_else(invalid)?;
+
+    let (left, right) = neighbours
+        .trim()
+        .strip_prefix('(')
+        .and_then(|neighbours| neighbours.strip_suffix(')'))
+        .and_then(|neighbours| neighbours.split_once(','))
+        .ok_or_else(invalid)?;
+
+    Ok((name.trim(), left.trim(), right.trim()))
+}
+
+fn build_map(input: &str) -> Result<(Vec<Node>, HashMap<String, usize>), Error> {
+    let specs: Vec<(&str, &str, &str)> =
+        parse::numbered_lines(input.lines().skip(2), 3, parse_node_spec)?;
+
+    let index: HashMap<String, usize> = specs
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _, _))| (name.to_string(), i))
+        .collect();
+
+    let lookup = |name: &str, line: usize| {
+        index
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::parse(format!("Unknown node '{name}'")).at_line(line))
+    };
+
+    let nodes = specs
+        .iter()
+        .enumerate()
+        .map(|(i, (name, left, right))| {
+            Ok(Node {
+                name: name.to_string(),
+                left: lookup(left, i + 3)?,
+                right: lookup(right, i + 3)?,
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok((nodes, index))
+}
+
+// the steps on which a ghost is at a node ending in Z
+pub struct Arrivals {
+    pub cycle: Cycle,
+    // those before the ghost has gone round its cycle once
+    pub steps: Vec<usize>,
+}
+
+impl Arrivals {
+    pub fn at(&self, steps: usize) -> bool {
+        self.steps.contains(&self.cycle.reduce(steps))
+    }
+}
+
+impl Network {
+    // a ghost's state is its node and how far through the directions it is
+    fn step_ghost(&self, &(node, direction): &(usize, usize)) -> (usize, usize) {
+        (
+            self.get_neighbour(node, &self.directions[direction]),
+            (direction + 1) % self.directions.len(),
+        )
+    }
+
+    pub fn arrivals(&self, starting_node: usize) -> Result<Arrivals, Error> {
+        let start = (starting_node, 0);
+        let cycle = cycle::brent(&start, |ghost| self.step_ghost(ghost))?;
+
+        let mut ghost = start;
+        let mut steps = Vec::new();
+        for step in 0..cycle.lead_in + cycle.period {
+            if self.nodes[ghost.0].name.ends_with('Z') {
+                steps.push(step);
+            }
+            ghost = self.step_ghost(&ghost);
+        }
+
+        Ok(Arrivals { cycle, steps })
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// the inverse of a modulo m, which must be coprime to it
+fn inverse(a: i128, m: i128) -> i128 {
+    let (mut old_r, mut r) = (a, m);
+    let (mut old_s, mut s) = (1, 0);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    old_s.rem_euclid(m)
+}
+
+// the x modulo lcm(m, n) with x ≡ a (mod m) and x ≡ b (mod n), if there is one
+fn combine(a: u64, m: u64, b: u64, n: u64) -> Option<u64> {
+    let g = gcd(m, n) as i128;
+    let (a, m, b, n) = (a as i128, m as i128, b as i128, n as i128);
+
+    if (b - a) % g != 0 {
+        return None;
+    }
+
+    // x = a + m * k, where m / g * k ≡ (b - a) / g modulo n / g
+    let reduced = n / g;
+    let k = ((b - a) / g).rem_euclid(reduced) * inverse(m / g % reduced, reduced) % reduced;
+
+    Some(((a + m * k) % (m / g * n)) as u64)
+}
+
+// the smallest number congruent to residue modulo modulus that's at least minimum, if it fits
+fn first_at_least(residue: u64, modulus: u64, minimum: u64) -> Option<u64> {
+    if residue >= minimum {
+        Some(residue)
+    } else {
+        (minimum - residue)
+            .div_ceil(modulus)
+            .checked_mul(modulus)?
+            .checked_add(residue)
+    }
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Network;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let directions: Vec<Direction> = input
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Direction::try_from(c).map_err(|e| e.at(1, i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        if directions.is_empty() {
+            return Err(Error::parse("Expected a list of directions").at_line(1));
+        }
+
+        let (nodes, index) = build_map(input)?;
+
+        Ok(Network {
+            directions,
+            nodes,
+            index,
+        })
+    }
+
+    fn part1(network: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let start = *network
+            .index
+            .get("AAA")
+            .ok_or_else(|| Error::invalid_state("There is no node AAA to start from"))?;
+
+        network
+            .count_steps(start, |name| name == "ZZZ")
+            .ok_or(Error::NoSolution)
+    }
+
+    fn part2(network: &Self::Parsed) -> Result<Self::Answer, Error> {
+        let ghosts: Vec<Arrivals> = network
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.name.ends_with('A'))
+            .map(|(starting_node, node)| {
+                let arrivals = network.arrivals(starting_node)?;
+                tracing::debug!(
+                    start = node.name,
+                    lead_in = arrivals.cycle.lead_in,
+                    period = arrivals.cycle.period,
+                    steps = ?arrivals.steps,
+                    "cycle"
+                );
+                Ok(arrivals)
+            })
+            .collect::<Result<_, Error>>()?;
+
+        if ghosts.is_empty() {
+            return Err(Error::invalid_state(
+                "There are no nodes ending in A to start from",
+            ));
+        }
+
+        // until every ghost is going round its cycle, arrivals don't repeat, so check each step
+        let lead_in = ghosts
+            .iter()
+            .map(|ghost| ghost.cycle.lead_in)
+            .max()
+            .unwrap_or_default();
+
+        if let Some(steps) = (0..lead_in).find(|&steps| ghosts.iter().all(|g| g.at(steps))) {
+            return Ok(steps as u64);
+        }
+
+        // from then on, each ghost arrives on steps congruent to some residues of its period
+        let mut residues = vec![0];
+        let mut modulus = 1;
+
+        for ghost in &ghosts {
+            cancel::checkpoint()?;
+
+            let period = ghost.cycle.period as u64;
+            // past a u64, the residues can't be combined any further
+            let combined = (modulus / gcd(modulus, period))
+                .checked_mul(period)
+                .ok_or(Error::NoSolution)?;
+            let ghost_residues: Vec<u64> = ghost
+                .steps
+                .iter()
+                .filter(|&&steps| steps >= ghost.cycle.lead_in)
+                .map(|&steps| steps as u64 % period)
+                .collect();
+
+            residues = residues
+                .iter()
+                .flat_map(|&a| {
+                    ghost_residues
+                        .iter()
+                        .filter_map(move |&b| combine(a, modulus, b, period))
+                })
+                .collect();
+            residues.sort();
+            residues.dedup();
+
+            modulus = combined;
+        }
+
+        residues
+            .into_iter()
+            .filter_map(|residue| first_at_least(residue, modulus, lead_in as u64))
+            .min()
+            .ok_or(Error::NoSolution)
+    }
+}
+
+// more nodes than that are too many to read
+const MAX_WALK: usize = 10_000;
+
+fn find_node(network: &Network, name: &str) -> Result<usize, String> {
+    network
+        .index
+        .get(name)
+        .copied()
+        .ok_or_else(|| format!("There is no node {name}"))
+}
+
+impl Repl for Day8 {
+    type State = Network;
+
+    fn start(network: Self::Parsed) -> Result<Self::State, Error> {
+        Ok(network)
+    }
+
+    fn commands() -> Vec<Command<Self::State>> {
+        vec![
+            Command {
+                name: "node",
+                arguments: "NODE",
+                help: "Show where a node leads left and right",
+                run: |network, arguments| {
+                    let name: String = repl::argument(arguments, 0, "a node")?;
+                    let node = &network.nodes[find_node(network, &name)?];
+
+                    Ok(format!(
+                        "{} = ({}, {})",
+                        node.name, network.nodes[node.left].name, network.nodes[node.right].name
+                    ))
+                },
+            },
+            Command {
+                name: "walk",
+                arguments: "NODE STEPS",
+                help: "Follow the directions from a node, listing each node reached",
+                run: |network, arguments| {
+                    let name: String = repl::argument(arguments, 0, "a node")?;
+                    let steps: usize = repl::argument(arguments, 1, "a number of steps")?;
+                    if steps > MAX_WALK {
+                        return Err(format!("Expected at most {MAX_WALK} steps, found {steps}"));
+                    }
+
+                    let mut current_node = find_node(network, &name)?;
+                    let mut names = vec![name];
+                    for direction in network.directions.iter().cycle().take(steps) {
+                        current_node = network.get_neighbour(current_node, direction);
+                        names.push(network.nodes[current_node].name.clone());
+                    }
+
+                    Ok(names.join(" "))
+                },
+            },
+        ]
+    }
+}
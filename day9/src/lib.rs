@@ -0,0 +1,70 @@
+use common::{parse, Error, Solution};
+
+pub fn parse_line(line: &str) -> Result<Vec<i64>, Error> {
+    line.split_whitespace().map(parse::number).collect()
+}
+
+// None once a difference doesn't fit in an i64
+fn differences(numbers: &[i64]) -> Option<Vec<i64>> {
+    numbers.windows(2).map(|w| w[1].checked_sub(w[0])).collect()
+}
+
+fn next_value(numbers: &[i64]) -> Option<i64> {
+    match numbers.last() {
+        Some(&last) if numbers.iter().any(|&n| n != 0) => {
+            next_value(&differences(numbers)?)?.checked_add(last)
+        }
+        _ => Some(0),
+    }
+}
+
+fn previous_value(numbers: &[i64]) -> Option<i64> {
+    match numbers.first() {
+        Some(&first) if numbers.iter().any(|&n| n != 0) => {
+            first.checked_sub(previous_value(&differences(numbers)?)?)
+        }
+        _ => Some(0),
+    }
+}
+
+pub fn calculate_next_value(numbers: &[i64]) -> Result<i64, Error> {
+    next_value(numbers).ok_or_else(|| Error::invalid_state("The next value doesn't fit in an i64"))
+}
+
+pub fn calculate_previous_value(numbers: &[i64]) -> Result<i64, Error> {
+    previous_value(numbers)
+        .ok_or_else(|| Error::invalid_state("The previous value doesn't fit in an i64"))
+}
+
+fn sum(mut values: impl Iterator<Item = Result<i64, Error>>) -> Result<i64, Error> {
+    values.try_fold(0_i64, |total, value| {
+        total
+            .checked_add(value?)
+            .ok_or_else(|| Error::invalid_state("The sum doesn't fit in an i64"))
+    })
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse::lines(input, parse_line)
+    }
+
+    fn part1(histories: &Self::Parsed) -> Result<Self::Answer, Error> {
+        sum(histories
+            .iter()
+            .map(|history| calculate_next_value(history)))
+    }
+
+    fn part2(histories: &Self::Parsed) -> Result<Self::Answer, Error> {
+        sum(histories
+            .iter()
+            .map(|history| calculate_previous_value(history)))
+    }
+}
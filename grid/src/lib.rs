@@ -0,0 +1,304 @@
+mod position;
+
+pub use crate::position::{Direction, Position};
+use common::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+const NEIGHBOURHOOD_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// a rectangular grid, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T> {
+    // parses one cell per character, tagging any failure with its line and column
+    pub fn parse(
+        input: &str,
+        mut parse: impl FnMut(char) -> Result<T, Error>,
+    ) -> Result<Self, Error> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut columns = None;
+        let mut rows = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+
+            for (column, c) in line.chars().enumerate() {
+                cells.push(parse(c).map_err(|e| e.at(row + 1, column + 1))?);
+            }
+
+            let row_length = cells.len() - row_start;
+
+            match columns {
+                None => columns = Some(row_length),
+                Some(columns) if columns != row_length => {
+                    return Err(Error::parse(format!(
+                        "Expected {columns} columns, found {row_length}"
+                    ))
+                    .at_line(row + 1))
+                }
+                Some(_) => {}
+            }
+
+            rows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            rows,
+            columns: columns.unwrap_or(0),
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+        let number_of_rows = rows.len();
+        let columns = rows.first().map_or(0, Vec::len);
+
+        if let Some(i) = rows.iter().position(|row| row.len() != columns) {
+            return Err(Error::invalid_state(format!(
+                "Row {i} has {} columns, expected {columns}",
+                rows[i].len()
+            )));
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            rows: number_of_rows,
+            columns,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.rows && position.column < self.columns
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then_some(position.row * self.columns + position.column)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    // None when the step would leave the grid
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|&p| self.contains(p))
+    }
+
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    // includes the diagonals
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURHOOD_8
+            .into_iter()
+            .filter_map(move |offset| position.offset(offset))
+            .filter(|&p| self.contains(p))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let columns = self.columns;
+        (0..self.rows)
+            .flat_map(move |row| (0..columns).map(move |column| Position::new(row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.columns.max(1))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.columns).map(|column| self.column(column))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(rows: usize, columns: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; rows * columns],
+            rows,
+            columns,
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            cells: self.iter_columns().flatten().cloned().collect(),
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            cells: self
+                .iter_columns()
+                .flat_map(|column| column.collect::<Vec<_>>().into_iter().rev())
+                .cloned()
+                .collect(),
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Self {
+        Self {
+            cells: (0..self.columns)
+                .rev()
+                .flat_map(|column| self.column(column))
+                .cloned()
+                .collect(),
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{position:?} is outside a {}x{} grid",
+                self.rows, self.columns
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside a {rows}x{columns} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Direction, Grid, Position};
+    use common::Error;
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(input, Ok).expect("Failed to parse grid")
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let input = "#..\n.#.\n..#\n#.#";
+
+        assert_eq!(parse(input).to_string(), input);
+    }
+
+    #[test]
+    fn test_ragged_rows_are_rejected() {
+        let error = Grid::parse("..\n...", Ok).expect_err("Parsed a ragged grid");
+
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let grid = parse("ab\ncd\nef");
+
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "bdf\nace");
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_anticlockwise().to_string(),
+            grid.to_string()
+        );
+    }
+
+    #[test]
+    fn test_neighbours_stay_inside() {
+        let grid = parse("...\n...");
+        let corner = Position::new(1, 2);
+
+        assert_eq!(grid.neighbours(corner).count(), 2);
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.step(corner, Direction::South), None);
+        assert_eq!(
+            grid.step(corner, Direction::North),
+            Some(Position::new(0, 2))
+        );
+    }
+}